@@ -0,0 +1,152 @@
+//! Timing harness comparing [`List::push`] against [`Vec::push`] and the `push` implementation that preceded the cold `grow()` path.
+//! Pushes `1_000_000` items spread over `16` lists, repeated for a number of rounds (`200` by default).
+//!
+//! ## Usage
+//! ```text
+//! cargo run --release --example push [rounds]
+//! ```
+
+
+#![allow(clippy::needless_return)]
+
+
+use lists::dynamic::{List, GrowthPolicy};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use core::mem::{size_of, align_of};
+use core::ptr::{NonNull, drop_in_place, slice_from_raw_parts_mut};
+use std::alloc;
+
+
+const ITEMS: usize = 1_000_000;
+const LISTS: usize = 16;
+
+
+/// Copy of the previous [`List::push`], kept as a baseline for comparison.
+struct OldList<T> {
+    ptr: NonNull<T>,
+    capacity: usize,
+    len: usize,
+    growth: GrowthPolicy,
+}
+
+
+impl<T> OldList<T> {
+    #[inline]
+    fn new() -> Self {
+        return Self {
+            ptr: NonNull::dangling(),
+            capacity: 0,
+            len: 0,
+            growth: GrowthPolicy::default(),
+        };
+    }
+
+    fn push(&mut self, value: T) {
+        if self.capacity == 0 {
+            let capacity = self.growth.grow(0);
+            let layout = alloc::Layout::array::<T>(capacity)
+                .expect("Could not allocate memory.");
+
+            let ptr = NonNull::new(
+                unsafe { alloc::alloc(layout) } as *mut T
+            ).expect("Could not allocate memory.");
+
+            unsafe { ptr.as_ptr().write(value); }
+
+            self.ptr = ptr;
+            self.capacity = capacity;
+        }
+
+        else if self.len < self.capacity {
+            let offset = self.len
+                .checked_mul(size_of::<T>())
+                .expect("Cannot reach memory location.");
+
+            assert!(offset < isize::MAX as usize, "Wrapped `isize`, cannot reach memory location.");
+
+            unsafe { self.ptr.as_ptr().add(self.len).write(value); }
+        }
+
+        else {
+            let new_capacity = self.growth.grow(self.capacity);
+
+            let size = size_of::<T>() * self.capacity;
+            let align = align_of::<T>();
+            let ptr;
+
+            size.checked_add(size % align)
+                .expect("Cannot reallocate memory.");
+
+            unsafe {
+                let layout = alloc::Layout::from_size_align_unchecked(size, align);
+
+                ptr = NonNull::new(
+                    alloc::realloc(
+                        self.ptr.as_ptr() as *mut u8,
+                        layout,
+                        size_of::<T>() * new_capacity
+                    ) as *mut T
+                ).expect("Cannot reallocate memory.");
+
+                ptr.as_ptr().add(self.len).write(value);
+            }
+
+            self.ptr = ptr;
+            self.capacity = new_capacity;
+        }
+
+        self.len += 1;
+    }
+}
+
+
+impl<T> Drop for OldList<T> {
+    fn drop(&mut self) {
+        unsafe {
+            drop_in_place(slice_from_raw_parts_mut(self.ptr.as_ptr(), self.len));
+
+            if self.capacity == 0 { return; }
+
+            let layout = alloc::Layout::array::<T>(self.capacity).unwrap();
+            alloc::dealloc(self.ptr.as_ptr() as *mut u8, layout);
+        }
+    }
+}
+
+
+/// Runs `rounds` rounds of pushing [`ITEMS`] items round-robin into [`LISTS`] lists created by `new`, returning the total time taken.
+fn time<L>(rounds: usize, new: impl Fn() -> L, push: impl Fn(&mut L, usize)) -> Duration {
+    let start = Instant::now();
+
+    for _ in 0 .. rounds {
+        let mut lists: Vec<L> = (0 .. LISTS).map(|_| new()).collect();
+
+        for i in 0 .. ITEMS {
+            push(&mut lists[i % LISTS], black_box(i));
+        }
+
+        black_box(&lists);
+    }
+
+    return start.elapsed();
+}
+
+
+fn main() {
+    let rounds = std::env::args().nth(1)
+        .map(|x| x.parse().expect("Rounds must be a number."))
+        .unwrap_or(200);
+
+    let results = [
+        ("List::push", time(rounds, List::new, List::push)),
+        ("Vec::push", time(rounds, Vec::new, Vec::push)),
+        ("previous List::push", time(rounds, OldList::new, OldList::push)),
+    ];
+
+    println!("{} rounds of {} items over {} lists:", rounds, ITEMS, LISTS);
+
+    for (name, elapsed) in results.iter() {
+        println!("  {:<20} {:>8.3}s", name, elapsed.as_secs_f64());
+    }
+}
@@ -12,26 +12,99 @@
 mod tests;
 
 
-use core::ptr::{NonNull, slice_from_raw_parts_mut};
-use core::iter::{IntoIterator, FromIterator};
-use core::slice::from_raw_parts_mut;
-use core::mem::{size_of, align_of};
-use core::ops::{Index, IndexMut};
-use core::cmp::{Eq, PartialEq};
+use core::ptr::{NonNull, slice_from_raw_parts_mut, copy_nonoverlapping, copy};
+use core::iter::{Iterator, IntoIterator, FromIterator, Extend, DoubleEndedIterator, ExactSizeIterator, FusedIterator};
+use core::mem::{size_of, align_of, ManuallyDrop, MaybeUninit};
+use core::ops::{Index, IndexMut, Add, AddAssign, Deref, DerefMut, Range, RangeBounds, Bound};
+use core::ops::{RangeFrom, RangeTo, RangeFull, RangeInclusive, RangeToInclusive};
+use core::cmp::{Eq, PartialEq, Ord, PartialOrd, Ordering};
 use core::ptr::drop_in_place;
+use core::slice::{self, from_raw_parts, from_raw_parts_mut};
+use core::borrow::{Borrow, BorrowMut};
+use core::hash::{Hash, Hasher};
 use core::option::Option;
 use core::fmt;
 
 use std::alloc;
+use std::error::Error;
+use std::boxed::Box;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::collections::BinaryHeap;
+use core::cmp::Reverse;
 
+use crate::linked::{SinglyLinkedList, DoublyLinkedList};
 
-/// The `capacity` will be multiplied by this whenever reallocation is needed.
+
+/// The `capacity` will be multiplied by this whenever reallocation is needed, under the default [`GrowthPolicy`].
 pub const RESIZE_MULTIPLIER: usize = 2;
 
-/// The initial `capacity` when the first `push()` is called for the [`List`].
+/// The initial `capacity` when the first `push()` is called for the [`List`], under a [`GrowthPolicy::Multiply`].
 pub const INITIAL_CAPACITY: usize = 4;
 
 
+/// Determines how the `capacity` of a [`List`] grows whenever reallocation is needed.
+/// The default policy multiplies the `capacity` by [`RESIZE_MULTIPLIER`].
+/// 
+/// ## Example
+/// ```rust
+/// let mut list = List::with_growth(GrowthPolicy::Increment(8));
+/// list.push(1);
+/// 
+/// assert_eq!(list.capacity(), 8);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GrowthPolicy {
+    /// Multiplies the `capacity` by `numerator / denominator`, `Multiply(3, 2)` results in `1.5×` growth.
+    /// The first allocation holds [`INITIAL_CAPACITY`] items. The `denominator` must be non-zero, see [`List::with_growth()`].
+    Multiply(usize, usize),
+
+    /// Increases the `capacity` by a fixed number of items.
+    Increment(usize),
+}
+
+
+impl GrowthPolicy {
+    /// Returns the `capacity` that should follow the given `capacity`, always at least one greater.
+    /// 
+    /// ## Panics
+    /// Panics if the policy is a [`GrowthPolicy::Multiply`] with a `denominator` of `0`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// assert_eq!(GrowthPolicy::Multiply(3, 2).grow(8), 12);
+    /// assert_eq!(GrowthPolicy::Increment(8).grow(8), 16);
+    /// ```
+    #[inline]
+    pub const fn grow(&self, capacity: usize) -> usize {
+        self.assert_valid();
+
+        let grown = match *self {
+            GrowthPolicy::Multiply(_, _) if capacity == 0 => INITIAL_CAPACITY,
+            GrowthPolicy::Multiply(numerator, denominator) => capacity.saturating_mul(numerator) / denominator,
+            GrowthPolicy::Increment(increment) => capacity.saturating_add(increment),
+        };
+
+        let minimum = capacity.saturating_add(1);
+        return if grown > minimum { grown } else { minimum };
+    }
+
+    /// Panics if the policy is a [`GrowthPolicy::Multiply`] with a `denominator` of `0`.
+    #[inline]
+    const fn assert_valid(&self) {
+        if let GrowthPolicy::Multiply(_, 0) = *self { panic!("Growth denominator must be non-zero."); }
+    }
+}
+
+
+impl Default for GrowthPolicy {
+    #[inline]
+    fn default() -> Self {
+        return GrowthPolicy::Multiply(RESIZE_MULTIPLIER, 1);
+    }
+}
+
+
 /// A one-dimensional, dynamically allocated sequence, known more commonly as a [`List`].
 pub struct List<T> {
     /// `ptr` to the first item within the [`List`].
@@ -42,20 +115,490 @@ pub struct List<T> {
     
     /// The `len` of the [`List`] represents how many items are present within.
     len: usize,
+
+    /// The [`GrowthPolicy`] used whenever the [`List`] needs to reallocate.
+    growth: GrowthPolicy,
+}
+
+
+/// Error returned by the fallible allocation methods of a [`List`], such as [`List::try_reserve()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The requested `capacity` exceeded the maximum size of an allocation.
+    CapacityOverflow,
+
+    /// The allocator failed to provide memory for the given `layout`.
+    AllocError {
+        /// Layout of the allocation that failed.
+        layout: alloc::Layout,
+    },
+}
+
+
+impl fmt::Display for TryReserveError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            TryReserveError::CapacityOverflow => write!(f, "Capacity overflow."),
+            TryReserveError::AllocError { layout } => write!(f, "Could not allocate {} bytes of memory.", layout.size()),
+        };
+    }
+}
+
+
+impl Error for TryReserveError {  }
+
+
+/// Error returned by [`List::get_many_mut()`] when the requested indices cannot be borrowed together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GetManyMutError {
+    /// An index was greater than or equal to the `len` of the [`List`].
+    IndexOutOfBounds(usize),
+
+    /// An index was requested more than once.
+    OverlappingIndices(usize),
+}
+
+
+impl fmt::Display for GetManyMutError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            GetManyMutError::IndexOutOfBounds(index) => write!(f, "Index '{}' out of bounds.", index),
+            GetManyMutError::OverlappingIndices(index) => write!(f, "Index '{}' requested more than once.", index),
+        };
+    }
+}
+
+
+impl Error for GetManyMutError {  }
+
+
+/// Deallocates a buffer of `capacity` items without dropping any of them.
+/// 
+/// ## Safety
+/// - `ptr` must have been allocated with the global allocator for exactly `capacity` items of `T`.
+/// - Alternatively, `capacity` must be `0` or `T` must be zero-sized, in which case nothing is deallocated.
+#[inline]
+unsafe fn deallocate<T>(ptr: NonNull<T>, capacity: usize) {
+    if capacity == 0 || size_of::<T>() == 0 { return; }
+
+    let layout = alloc::Layout::from_size_align_unchecked(
+        size_of::<T>() * capacity,
+        align_of::<T>()
+    );
+
+    alloc::dealloc(ptr.as_ptr() as *mut u8, layout);
+}
+
+
+/// Resolves `range` into a [`Range`] of indices within a [`List`] of `len` items.
+/// 
+/// ## Panics
+/// Panics if the `range` is out of bounds, or if it starts after it ends.
+#[inline]
+fn resolve_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).unwrap_or_else(|| panic!("Range start '{}' out of bounds.", start)),
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).unwrap_or_else(|| panic!("Range end '{}' out of bounds.", end)),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    if start > end { panic!("Range starts at '{}' but ends at '{}'.", start, end); }
+    if end > len { panic!("Range end '{}' out of bounds.", end); }
+
+    return start..end;
+}
+
+
+/// Consuming [`Iterator`] over the items of a [`List`], the list's struct for their `IntoIter` trait.
+pub struct IntoIter<T> {
+    /// `ptr` to the first item of the consumed [`List`]'s buffer.
+    ptr: NonNull<T>,
+
+    /// The `capacity` of the consumed [`List`], required to deallocate its buffer.
+    capacity: usize,
+
+    /// Index of the next item to be yielded from the `front`.
+    start: usize,
+
+    /// Index one past the next item to be yielded from the `back`.
+    end: usize,
+}
+
+
+/// Consuming [`Iterator`] that yields owned [`List`]s of up to `chunk_size` items, created by [`List::into_chunks()`].
+pub struct IntoChunks<T> {
+    /// [`IntoIter`] owning the items that have yet to be moved into a chunk.
+    iter: IntoIter<T>,
+
+    /// Maximum number of items within each yielded [`List`].
+    chunk_size: usize,
+}
+
+
+/// Draining [`Iterator`] that removes a range of items from a [`List`] and yields them by value, created by [`List::drain()`].
+/// Any items that are not yielded are dropped alongside the [`Drain`], after which the gap within the [`List`] is closed.
+pub struct Drain<'a, T> {
+    /// The [`List`] being drained, its `len` is truncated to the start of the range while the [`Drain`] is alive.
+    list: &'a mut List<T>,
+
+    /// Index of the next item to be yielded from the `front`.
+    start: usize,
+
+    /// Index one past the next item to be yielded from the `back`.
+    end: usize,
+
+    /// Index of the first item after the drained range.
+    tail_start: usize,
+
+    /// Number of items after the drained range.
+    tail_len: usize,
+}
+
+
+/// [`Iterator`] that removes and yields the items of a [`List`] matching a predicate, created by [`List::extract_if()`].
+/// Items that are not yet visited when the [`ExtractIf`] is dropped are kept, and the [`List`] is compacted.
+pub struct ExtractIf<'a, T, F: FnMut(&mut T) -> bool> {
+    /// The [`List`] being filtered, its `len` is set to `0` while the [`ExtractIf`] is alive.
+    list: &'a mut List<T>,
+
+    /// Predicate that decides whether an item is extracted.
+    predicate: F,
+
+    /// Index of the next item to be visited.
+    index: usize,
+
+    /// Number of items that have been extracted so far.
+    extracted: usize,
+
+    /// The `len` of the [`List`] before the [`ExtractIf`] was created.
+    len: usize,
+}
+
+
+impl<T> IntoIter<T> {
+    /// Returns the number of items that have yet to be yielded.
+    #[inline]
+    const fn remaining(&self) -> usize {
+        return self.end - self.start;
+    }
+}
+
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end { return None; }
+
+        // SAFETY: `start` is within the initialized region and is advanced past the read item, so it is never read twice.
+        let value = unsafe { self.ptr.as_ptr().add(self.start).read() };
+        self.start += 1;
+
+        return Some(value);
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.remaining(), Some(self.remaining()));
+    }
+}
+
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end { return None; }
+
+        // SAFETY: `end - 1` is within the initialized region and `end` is moved before it, so it is never read twice.
+        self.end -= 1;
+        return Some(unsafe { self.ptr.as_ptr().add(self.end).read() });
+    }
+}
+
+
+impl<T> ExactSizeIterator for IntoIter<T> {  }
+impl<T> FusedIterator for IntoIter<T> {  }
+
+
+impl<T> Drop for IntoIter<T> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            drop_in_place(
+                slice_from_raw_parts_mut(self.ptr.as_ptr().add(self.start), self.remaining())
+            );
+
+            deallocate(self.ptr, self.capacity);
+        }
+    }
+}
+
+
+impl<T> Iterator for IntoChunks<T> {
+    type Item = List<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let iter = &mut self.iter;
+        if iter.start == iter.end { return None; }
+
+        let size = self.chunk_size.min(iter.remaining());
+        let mut chunk = List::with_capacity(size);
+
+        /*
+            SAFETY:
+            - `start .. start + size` is within the initialized region of the buffer.
+            - `start` is advanced past the moved items, so they will never be read or dropped again by the iterator.
+        */
+        unsafe {
+            copy_nonoverlapping(iter.ptr.as_ptr().add(iter.start), chunk.ptr.as_ptr(), size);
+        }
+
+        chunk.len = size;
+        iter.start += size;
+
+        return Some(chunk);
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = self.iter.remaining().div_ceil(self.chunk_size);
+        return (chunks, Some(chunks));
+    }
+}
+
+
+impl<T> DoubleEndedIterator for IntoChunks<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let iter = &mut self.iter;
+        if iter.start == iter.end { return None; }
+
+        let size = match iter.remaining() % self.chunk_size {
+            0 => self.chunk_size,
+            size => size,
+        };
+
+        let mut chunk = List::with_capacity(size);
+
+        /*
+            SAFETY:
+            - `end - size .. end` is within the initialized region of the buffer.
+            - `end` is moved before the moved items, so they will never be read or dropped again by the iterator.
+        */
+        unsafe {
+            copy_nonoverlapping(iter.ptr.as_ptr().add(iter.end - size), chunk.ptr.as_ptr(), size);
+        }
+
+        chunk.len = size;
+        iter.end -= size;
+
+        return Some(chunk);
+    }
+}
+
+
+impl<T> ExactSizeIterator for IntoChunks<T> {  }
+impl<T> FusedIterator for IntoChunks<T> {  }
+
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end { return None; }
+
+        // SAFETY: `start` is within the drained range and is advanced past the read item, so it is never read twice.
+        let value = unsafe { self.list.ptr.as_ptr().add(self.start).read() };
+        self.start += 1;
+
+        return Some(value);
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.end - self.start, Some(self.end - self.start));
+    }
+}
+
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end { return None; }
+
+        // SAFETY: `end - 1` is within the drained range and `end` is moved before it, so it is never read twice.
+        self.end -= 1;
+        return Some(unsafe { self.list.ptr.as_ptr().add(self.end).read() });
+    }
+}
+
+
+impl<T> ExactSizeIterator for Drain<'_, T> {  }
+impl<T> FusedIterator for Drain<'_, T> {  }
+
+
+impl<T, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'_, T, F> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.len {
+            /*
+                SAFETY:
+                - `index < len`, so the item is initialized and has not yet been moved or read.
+                - Extracted items are read out and counted before the next visit, so they are never touched again.
+                - Kept items are moved down by `extracted` slots, which are either vacated or the item's own slot.
+            */
+            unsafe {
+                let ptr = self.list.ptr.as_ptr();
+                let current = ptr.add(self.index);
+
+                let extract = (self.predicate)(&mut *current);
+                self.index += 1;
+
+                if extract {
+                    self.extracted += 1;
+                    return Some(current.read());
+                }
+
+                if self.extracted > 0 {
+                    copy_nonoverlapping(current, ptr.add(self.index - 1 - self.extracted), 1);
+                }
+            }
+        }
+
+        return None;
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (0, Some(self.len - self.index));
+    }
+}
+
+
+impl<T, F: FnMut(&mut T) -> bool> Drop for ExtractIf<'_, T, F> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: Unvisited items are initialized, and are moved into the gap left by extracted items.
+        unsafe {
+            let ptr = self.list.ptr.as_ptr();
+            copy(ptr.add(self.index), ptr.add(self.index - self.extracted), self.len - self.index);
+        }
+
+        self.list.len = self.len - self.extracted;
+    }
+}
+
+
+impl<T> Drop for Drain<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        /// Closes the gap left by the drained range, even if dropping one of the remaining items panics.
+        struct TailGuard<'r, 'a, T>(&'r mut Drain<'a, T>);
+
+        impl<T> Drop for TailGuard<'_, '_, T> {
+            #[inline]
+            fn drop(&mut self) {
+                let drain = &mut *self.0;
+                let len = drain.list.len;
+
+                // SAFETY: Both regions are within the allocation, `copy()` handles them overlapping.
+                unsafe {
+                    let ptr = drain.list.ptr.as_ptr();
+                    copy(ptr.add(drain.tail_start), ptr.add(len), drain.tail_len);
+                }
+
+                drain.list.len = len + drain.tail_len;
+            }
+        }
+
+        let remaining = slice_from_raw_parts_mut(
+            // SAFETY: `start` is within the drained range, or one past it.
+            unsafe { self.list.ptr.as_ptr().add(self.start) },
+            self.end - self.start
+        );
+
+        self.start = self.end;
+        let _guard = TailGuard(self);
+
+        // SAFETY: The remaining items are initialized and were never yielded, the iterator is emptied beforehand.
+        unsafe { drop_in_place(remaining); }
+    }
 }
 
 
 impl<T> List<T> {
     /// Creates a new, and empty [`List`].
+    /// Lists of zero-sized types never allocate, and always have a `capacity` of `usize::MAX`.
     #[inline]
     pub const fn new() -> Self {
         return Self {
             ptr: NonNull::dangling(),
-            capacity: 0,
+            capacity: if size_of::<T>() == 0 { usize::MAX } else { 0 },
             len : 0,
+            growth: GrowthPolicy::Multiply(RESIZE_MULTIPLIER, 1),
         }
     }
 
+    /// Creates a new, and empty [`List`] that grows according to the given [`GrowthPolicy`].
+    /// 
+    /// ## Panics
+    /// Panics if `growth` is a [`GrowthPolicy::Multiply`] with a `denominator` of `0`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = List::with_growth(GrowthPolicy::Multiply(3, 2));
+    /// list.extend([1, 2, 3, 4, 5]);
+    /// 
+    /// assert_eq!(list.capacity(), 6);
+    /// ```
+    #[inline]
+    pub const fn with_growth(growth: GrowthPolicy) -> Self {
+        growth.assert_valid();
+
+        let mut list = Self::new();
+        list.growth = growth;
+
+        return list;
+    }
+
+    /// Returns the [`GrowthPolicy`] of the [`List`].
+    #[inline]
+    pub const fn growth(&self) -> GrowthPolicy {
+        return self.growth;
+    }
+
+    /// Sets the [`GrowthPolicy`] used by the [`List`] for future reallocations.
+    /// 
+    /// ## Panics
+    /// Panics if `growth` is a [`GrowthPolicy::Multiply`] with a `denominator` of `0`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3, 4];
+    /// list.set_growth(GrowthPolicy::Increment(2));
+    /// 
+    /// list.push(5);
+    /// assert_eq!(list.capacity(), 6);
+    /// ```
+    #[inline]
+    pub fn set_growth(&mut self, growth: GrowthPolicy) {
+        growth.assert_valid();
+        self.growth = growth;
+    }
+
     /// Returns the `capacity` field of the [`List`].
     #[inline]
     pub const fn capacity(&self) -> usize {
@@ -112,99 +655,411 @@ impl<T> List<T> {
     /// ```
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
-        /* TODO: Allow zero-sized types */
-        assert!(size_of::<T>() > 0, "Zero-sized types are not allowed.");
-        
         let mut list = Self::new();
+        if capacity > 0 { list.reallocate(capacity); }
 
-        let layout = alloc::Layout::array::<T>(capacity)
-            .expect("Could not allocate memory.");
-        
-        let ptr = NonNull::new(
-            unsafe { alloc::alloc(layout) } as *mut T
-        ).expect("Could not allocate memory");
+        return list;
+    }
 
-        list.ptr = ptr;
-        list.capacity = capacity;
+    /// Fallible version of [`List::with_capacity()`], returning a [`TryReserveError`] instead of panicking or aborting when allocation fails.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = List::<i32>::try_with_capacity(3).unwrap();
+    /// assert_eq!(list.capacity(), 3);
+    /// 
+    /// assert!(List::<i32>::try_with_capacity(usize::MAX).is_err());
+    /// ```
+    #[inline]
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        let mut list = Self::new();
+        if capacity > 0 { list.try_reallocate(capacity)?; }
 
-        return list;
+        return Ok(list);
     }
 
-    /// Appends a new `value` into the [`List`].
+    /// Fallible version of [`List::push()`], returning a [`TryReserveError`] instead of panicking or aborting when allocation fails.
     /// 
     /// ## Example
     /// ```rust
     /// let mut list = List::new();
     /// 
-    /// list.push(1);
-    /// list.push(2);
-    /// list.push(3);
+    /// list.try_push(1).unwrap();
+    /// assert_eq!(list, list![1]);
+    /// ```
+    #[inline]
+    pub fn try_push(&mut self, value: T) -> Result<(), TryReserveError> {
+        if self.len == self.capacity { self.try_reserve(1)?; }
+
+        self.push(value);
+        return Ok(());
+    }
+
+    /// Appends a new `value` into the [`List`] only if it has spare `capacity`, this will never allocate.
+    /// Returns the `value` back within an `Err` if the [`List`] is full.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = List::with_capacity(1);
+    /// 
+    /// assert_eq!(list.push_within_capacity(1), Ok(()));
+    /// assert_eq!(list.push_within_capacity(2), Err(2));
+    /// assert_eq!(list, list![1]);
+    /// ```
+    #[inline]
+    pub fn push_within_capacity(&mut self, value: T) -> Result<(), T> {
+        if self.len == self.capacity { return Err(value); }
+
+        // SAFETY: `len < capacity`, so the item is written into the allocated, uninitialized region.
+        unsafe { self.ptr.as_ptr().add(self.len).write(value); }
+        self.len += 1;
+
+        return Ok(());
+    }
+
+    /// Appends a new `value` into the [`List`].
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = List::new();
+    /// 
+    /// list.push(1);
+    /// list.push(2);
+    /// list.push(3);
     /// 
     /// assert_eq!(list, list![1, 2, 3]);
     /// ```
+    #[inline]
     pub fn push(&mut self, value: T) {
+        let len = self.len;
+        if len == self.capacity { self.grow(); }
+
+        // SAFETY: `len < capacity`, so the item is written into the allocated, uninitialized region.
+        unsafe { self.ptr.as_ptr().add(len).write(value); }
+        self.len = len + 1;
+    }
+
+    /// Removes the last item of the [`List`], returning it, or `None` if the [`List`] is empty.
+    /// Time complexity is `O(1)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3];
+    /// 
+    /// assert_eq!(list.pop(), Some(3));
+    /// assert_eq!(list, list![1, 2]);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 { return None; }
+
+        self.len -= 1;
+
+        // SAFETY: The item at the old `len - 1` is initialized, and is no longer considered part of the list.
+        return Some(unsafe { self.ptr.as_ptr().add(self.len).read() });
+    }
+
+    /// Removes the first item of the [`List`], returning it, or `None` if the [`List`] is empty.
+    /// The remaining items are shifted to the left, time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3];
+    /// 
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(list, list![2, 3]);
+    /// ```
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 { return None; }
+        return Some(self.remove(0));
+    }
+
+    /// Inserts a `value` at `index` within the [`List`], shifting all items after it to the right.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Panics
+    /// Panics if `index` is greater than the [`List`]'s `len`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 3];
+    /// 
+    /// list.insert(1, 2);
+    /// list.insert(3, 4);
+    /// 
+    /// assert_eq!(list, list![1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, index: usize, value: T) {
+        let len = self.len;
+
+        if index > len { panic!("Index '{}' out of bounds.", index); }
+        if len == self.capacity { self.grow(); }
+
         /*
-            TODO:
-            - Cleanup Code
-            - Allow zero-sized types
+            SAFETY:
+            - `index <= len < capacity`, so both the shifted region and the written slot are within the allocation.
+            - `copy()` handles the overlapping source and destination regions.
         */
-        
-        assert!(size_of::<T>() > 0, "Zero-sized types are not allowed.");
+        unsafe {
+            let ptr = self.ptr.as_ptr().add(index);
+
+            copy(ptr, ptr.add(1), len - index);
+            ptr.write(value);
+        }
 
-        if self.capacity == 0 {
-            let layout = alloc::Layout::array::<T>(INITIAL_CAPACITY)
-                .expect("Could not allocate memory.");
-            
-            let ptr = NonNull::new(
-                unsafe { alloc::alloc(layout) } as *mut T
-            ).expect("Could not allocate memory.");
+        self.len = len + 1;
+    }
+
+    /// Removes and returns the item at `index` within the [`List`], shifting all items after it to the left.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Panics
+    /// Panics if `index` is out of bounds.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3];
+    /// 
+    /// assert_eq!(list.remove(1), 2);
+    /// assert_eq!(list, list![1, 3]);
+    /// ```
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len;
+        if index >= len { panic!("Index '{}' out of bounds.", index); }
+
+        /*
+            SAFETY:
+            - `index < len`, so the item is initialized and read out before being overwritten by the shift.
+            - `copy()` handles the overlapping source and destination regions, both of which are within the allocation.
+        */
+        unsafe {
+            let ptr = self.ptr.as_ptr().add(index);
+            let value = ptr.read();
+
+            copy(ptr.add(1), ptr, len - index - 1);
+            self.len = len - 1;
+
+            return value;
+        }
+    }
+
+    /// Removes and returns the item at `index` within the [`List`], replacing it with the last item.
+    /// This does not preserve ordering, but time complexity is `O(1)`.
+    /// 
+    /// ## Panics
+    /// Panics if `index` is out of bounds.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3, 4];
+    /// 
+    /// assert_eq!(list.swap_remove(0), 1);
+    /// assert_eq!(list, list![4, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.len;
+        if index >= len { panic!("Index '{}' out of bounds.", index); }
+
+        /*
+            SAFETY:
+            - `index < len`, so both the removed item and the last item are initialized.
+            - `copy()` is used as `index` may be the last item, in which case the regions overlap.
+        */
+        unsafe {
+            let ptr = self.ptr.as_ptr();
+            let value = ptr.add(index).read();
 
-            unsafe { ptr.as_ptr().write(value); }
-            
-            self.ptr = ptr;
-            self.capacity = INITIAL_CAPACITY;
+            copy(ptr.add(len - 1), ptr.add(index), 1);
+            self.len = len - 1;
+
+            return value;
         }
+    }
+
+    /// Grows the buffer of the [`List`] by at least one item according to its [`GrowthPolicy`].
+    /// Kept out of line and marked cold, so that [`List::push()`] remains small enough to inline.
+    #[cold]
+    #[inline(never)]
+    fn grow(&mut self) {
+        self.reserve(1);
+    }
+
+    /// Reserves capacity for at least `additional` more items to be pushed into the [`List`] using a single reallocation.
+    /// The `capacity` may grow beyond what was requested, following the usual growth of the [`List`], to avoid frequent reallocations.
+    /// Does nothing if the `capacity` is already sufficient.
+    /// 
+    /// ## Panics
+    /// Panics if the new `capacity` overflows `usize`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1];
+    /// list.reserve(10);
+    /// 
+    /// assert!(list.capacity() >= 11);
+    /// ```
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional)
+            .expect("Capacity overflow.");
+        
+        if required <= self.capacity { return; }
+
+        let capacity = required.max(self.growth.grow(self.capacity));
+
+        self.reallocate(capacity);
+    }
+
+    /// Reserves capacity for exactly `additional` more items to be pushed into the [`List`] using a single reallocation.
+    /// Does nothing if the `capacity` is already sufficient.
+    /// 
+    /// ## Panics
+    /// Panics if the new `capacity` overflows `usize`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1];
+    /// list.reserve_exact(10);
+    /// 
+    /// assert_eq!(list.capacity(), 11);
+    /// ```
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional)
+            .expect("Capacity overflow.");
+        
+        if required <= self.capacity { return; }
+        self.reallocate(required);
+    }
+
+    /// Fallible version of [`List::reserve()`], returning a [`TryReserveError`] instead of panicking or aborting when allocation fails.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1];
+    /// 
+    /// assert!(list.try_reserve(10).is_ok());
+    /// assert!(list.try_reserve(usize::MAX).is_err());
+    /// ```
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = self.len.checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        
+        if required <= self.capacity { return Ok(()); }
+
+        let capacity = required.max(self.growth.grow(self.capacity));
+
+        return self.try_reallocate(capacity);
+    }
+
+    /// Fallible version of [`List::reserve_exact()`], returning a [`TryReserveError`] instead of panicking or aborting when allocation fails.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1];
+    /// 
+    /// assert!(list.try_reserve_exact(10).is_ok());
+    /// assert_eq!(list.capacity(), 11);
+    /// ```
+    #[inline]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = self.len.checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        
+        if required <= self.capacity { return Ok(()); }
+        return self.try_reallocate(required);
+    }
+
+    /// Shrinks the `capacity` of the [`List`] as much as possible, down to its `len`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = List::with_capacity(10);
+    /// list.push(1); list.push(2);
+    /// 
+    /// list.shrink_to_fit();
+    /// assert_eq!(list.capacity(), 2);
+    /// ```
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(0);
+    }
+
+    /// Shrinks the `capacity` of the [`List`] down to `min_capacity`, or its `len` if that is greater.
+    /// Does nothing if the `capacity` is already smaller than the target.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = List::with_capacity(10);
+    /// list.push(1); list.push(2);
+    /// 
+    /// list.shrink_to(4);
+    /// assert_eq!(list.capacity(), 4);
+    /// 
+    /// list.shrink_to(0);
+    /// assert_eq!(list.capacity(), 2);
+    /// ```
+    #[inline]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        if size_of::<T>() == 0 { return; }
 
-        else if self.len < self.capacity {
-            let offset = self.len
-                .checked_mul(size_of::<T>())
-                .expect("Cannot reach memory location.");
-            
-            assert!(offset < isize::MAX as usize, "Wrapped `isize`, cannot reach memory location.");
+        let capacity = self.len.max(min_capacity);
+        if capacity >= self.capacity { return; }
 
-            unsafe { self.ptr.as_ptr().add(self.len).write(value); }
+        if capacity == 0 {
+            // SAFETY: The `List` is empty, so no items are lost by deallocating its buffer.
+            unsafe { deallocate(self.ptr, self.capacity); }
+
+            self.ptr = NonNull::dangling();
+            self.capacity = 0;
         }
 
         else {
-            let new_capacity = self.capacity.checked_mul(RESIZE_MULTIPLIER)
-                .expect("Capacity wrapped.");
-            
-            let size = size_of::<T>() * self.capacity;
-            let align = align_of::<T>();
-            let ptr;
-
-            size.checked_add(size % align)
-                .expect("Cannot reallocate memory.");
-    
-            unsafe {
-                let layout = alloc::Layout::from_size_align_unchecked(size, align);
-
-                ptr = NonNull::new(
-                    alloc::realloc(
-                        self.ptr.as_ptr() as *mut u8,
-                        layout,
-                        size_of::<T>() * new_capacity
-                    ) as *mut T
-                ).expect("Cannot reallocate memory.");
-                
-                ptr.as_ptr().add(self.len).write(value);
-            }
+            self.reallocate(capacity);
+        }
+    }
 
-            self.ptr = ptr;
-            self.capacity = new_capacity;
+    /// Reallocates the buffer of the [`List`] so that it can hold exactly `capacity` items.
+    /// `capacity` must be non-zero and no smaller than the `len` of the [`List`].
+    fn reallocate(&mut self, capacity: usize) {
+        match self.try_reallocate(capacity) {
+            Ok(()) => {  },
+            Err(TryReserveError::CapacityOverflow) => panic!("Capacity overflow."),
+            Err(TryReserveError::AllocError { layout }) => alloc::handle_alloc_error(layout),
         }
+    }
 
-        self.len += 1;
+    /// Fallible version of [`List::reallocate()`], returning an error instead of panicking or aborting.
+    fn try_reallocate(&mut self, capacity: usize) -> Result<(), TryReserveError> {
+        // Zero-sized types never allocate, their `capacity` is always `usize::MAX`.
+        if size_of::<T>() == 0 { return Ok(()); }
+        debug_assert!(capacity > 0 && capacity >= self.len);
+
+        let layout = alloc::Layout::array::<T>(capacity)
+            .map_err(|_| TryReserveError::CapacityOverflow)?;
+
+        let ptr = unsafe {
+            if self.capacity == 0 {
+                alloc::alloc(layout)
+            }
+
+            else {
+                // SAFETY: The current buffer was allocated with this exact layout, so it is known to be valid.
+                let old_layout = alloc::Layout::array::<T>(self.capacity).unwrap_unchecked();
+                alloc::realloc(self.ptr.as_ptr() as *mut u8, old_layout, layout.size())
+            }
+        };
+
+        self.ptr = NonNull::new(ptr as *mut T)
+            .ok_or(TryReserveError::AllocError { layout })?;
+        
+        self.capacity = capacity;
+        return Ok(());
     }
 
     /// Shortens the [`List`], keeping the first `len` items and dropping the rest.
@@ -238,87 +1093,1963 @@ impl<T> List<T> {
         }
     }
 
-    /// Returns a reference to the item at the given `index`.
+    /// Removes the first `count` items of the [`List`], dropping them and shifting the survivors to the front in a single move.
+    /// If `count` is greater than the [`List`]'s current length, every item is dropped.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3, 4, 5];
+    /// list.truncate_front(2);
+    /// 
+    /// assert_eq!(list, list![3, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn truncate_front(&mut self, count: usize) {
+        let len = self.len;
+        let count = count.min(len);
+
+        if count == 0 { return; }
+
+        /*
+            SAFETY:
+            - The first `count` items are initialized, and are dropped exactly once.
+            - The `len` of the list is zeroed beforehand, such that a panicking `drop_in_place()` leaks the survivors instead of double dropping.
+            - `copy()` handles the overlapping source and destination regions, both of which are within the allocation.
+        */
+        unsafe {
+            let ptr = self.ptr.as_ptr();
+            self.len = 0;
+
+            drop_in_place(slice_from_raw_parts_mut(ptr, count));
+            copy(ptr.add(count), ptr, len - count);
+
+            self.len = len - count;
+        }
+    }
+
+    /// Shortens the [`List`] from the front, keeping the last `len` items and dropping the rest.
+    /// If `len` is greater than the [`List`]'s current length, this has no effect.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3, 4, 5];
+    /// list.keep_last(2);
+    /// 
+    /// assert_eq!(list, list![4, 5]);
+    /// ```
+    #[inline]
+    pub fn keep_last(&mut self, len: usize) {
+        self.truncate_front(self.len.saturating_sub(len));
+    }
+
+    /// Forces the `len` of the [`List`] to `new_len`, without dropping or initializing any items.
+    /// Typically used after writing into the buffer returned by [`List::spare_capacity_mut()`].
+    /// 
+    /// ## Safety
+    /// - `new_len` must be less than or equal to the [`List`]'s `capacity`.
+    /// - The items at `old_len..new_len` must be initialized.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = List::with_capacity(3);
+    /// 
+    /// for (index, slot) in list.spare_capacity_mut().iter_mut().enumerate() {
+    ///     slot.write(index);
+    /// }
+    /// 
+    /// unsafe { list.set_len(3); }
+    /// assert_eq!(list, list![0, 1, 2]);
+    /// ```
+    #[inline]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.capacity);
+        self.len = new_len;
+    }
+
+    /// Returns the remaining spare capacity of the [`List`] as a slice of [`MaybeUninit<T>`].
+    /// Once items have been written, they can be committed with [`List::set_len()`].
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = List::with_capacity(4);
+    /// list.push(1);
+    /// 
+    /// let spare = list.spare_capacity_mut();
+    /// spare[0].write(2);
+    /// 
+    /// unsafe { list.set_len(2); }
+    /// assert_eq!(list, list![1, 2]);
+    /// ```
+    #[inline]
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        // SAFETY: The region `len..capacity` lies within the allocation, and `MaybeUninit<T>` has no validity requirements.
+        return unsafe {
+            from_raw_parts_mut(
+                self.ptr.as_ptr().add(self.len) as *mut MaybeUninit<T>,
+                self.capacity - self.len
+            )
+        };
+    }
+
+    /// Removes the items within `range` from the [`List`], returning a [`Drain`] that yields them by value.
+    /// Items that are not consumed are dropped alongside the [`Drain`], after which the remaining items are shifted to close the gap.
+    /// If the [`Drain`] is leaked, the [`List`] is left truncated to the start of `range`.
+    /// 
+    /// ## Panics
+    /// Panics if `range` is out of bounds, or if it starts after it ends.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3, 4, 5];
+    /// let drained: List<i32> = list.drain(1..3).collect();
+    /// 
+    /// assert_eq!(drained, list![2, 3]);
+    /// assert_eq!(list, list![1, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let len = self.len;
+        let Range { start, end } = resolve_range(range, len);
+
+        self.len = start;
+
+        return Drain {
+            list: self,
+            start,
+            end,
+            tail_start: end,
+            tail_len: len - end,
+        };
+    }
+
+    /// Retains only the items of the [`List`] for which `predicate` returns `true`, preserving their order.
+    /// The buffer is compacted in a single pass, time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3, 4, 5];
+    /// list.retain(|x| x % 2 == 1);
+    /// 
+    /// assert_eq!(list, list![1, 3, 5]);
+    /// ```
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut predicate: F) {
+        self.retain_mut(|x| predicate(x));
+    }
+
+    /// Retains only the items of the [`List`] for which `predicate` returns `true`, passing each item mutably.
+    /// The buffer is compacted in a single pass, time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3, 4, 5];
+    /// 
+    /// list.retain_mut(|x| {
+    ///     *x *= 10;
+    ///     return *x > 20;
+    /// });
+    /// 
+    /// assert_eq!(list, list![30, 40, 50]);
+    /// ```
+    #[inline]
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut predicate: F) {
+        /// Keeps the [`List`] consistent if `predicate` or a dropped item panics, by shifting unprocessed items over the gap.
+        struct RetainGuard<'a, T> {
+            list: &'a mut List<T>,
+            len: usize,
+            processed: usize,
+            deleted: usize,
+        }
+
+        impl<T> Drop for RetainGuard<'_, T> {
+            #[inline]
+            fn drop(&mut self) {
+                // SAFETY: Unprocessed items are initialized, and are moved into the gap left by deleted items.
+                unsafe {
+                    let ptr = self.list.ptr.as_ptr();
+                    copy(ptr.add(self.processed), ptr.add(self.processed - self.deleted), self.len - self.processed);
+                }
+
+                self.list.len = self.len - self.deleted;
+            }
+        }
+
+        let len = self.len;
+        self.len = 0;
+
+        let mut guard = RetainGuard { list: self, len, processed: 0, deleted: 0 };
+
+        while guard.processed < guard.len {
+            /*
+                SAFETY:
+                - `processed < len`, so the item is initialized and has not yet been moved or dropped.
+                - The counters are updated before dropping a rejected item, so the guard never touches it again.
+                - Kept items are moved down by `deleted` slots, which are either vacated or the item's own slot.
+            */
+            unsafe {
+                let ptr = guard.list.ptr.as_ptr();
+                let current = ptr.add(guard.processed);
+
+                if !predicate(&mut *current) {
+                    guard.processed += 1;
+                    guard.deleted += 1;
+                    drop_in_place(current);
+                    continue;
+                }
+
+                if guard.deleted > 0 {
+                    copy_nonoverlapping(current, ptr.add(guard.processed - guard.deleted), 1);
+                }
+
+                guard.processed += 1;
+            }
+        }
+    }
+
+    /// Returns an [`Iterator`] that removes and yields every item of the [`List`] for which `predicate` returns `true`.
+    /// Remaining items are compacted in place, preserving their order, within a single pass.
+    /// If the [`ExtractIf`] is dropped early, unvisited items are kept. If it is leaked, the [`List`] is left empty.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3, 4, 5, 6];
+    /// let evens: List<i32> = list.extract_if(|x| *x % 2 == 0).collect();
+    /// 
+    /// assert_eq!(evens, list![2, 4, 6]);
+    /// assert_eq!(list, list![1, 3, 5]);
+    /// ```
+    #[inline]
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, predicate: F) -> ExtractIf<'_, T, F> {
+        let len = self.len;
+        self.len = 0;
+
+        return ExtractIf {
+            list: self,
+            predicate,
+            index: 0,
+            extracted: 0,
+            len,
+        };
+    }
+
+    /// Removes consecutive items of the [`List`] for which `same_bucket` returns `true`, keeping the first of each run.
+    /// `same_bucket` is passed the current item followed by the last retained item, time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list!["a", "A", "b", "B", "b", "a"];
+    /// list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// 
+    /// assert_eq!(list, list!["a", "b", "a"]);
+    /// ```
+    #[inline]
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        /// Keeps the [`List`] consistent if `same_bucket` or a dropped item panics, by shifting unprocessed items over the gap.
+        struct DedupGuard<'a, T> {
+            list: &'a mut List<T>,
+            len: usize,
+            read: usize,
+            write: usize,
+        }
+
+        impl<T> Drop for DedupGuard<'_, T> {
+            #[inline]
+            fn drop(&mut self) {
+                // SAFETY: Unprocessed items are initialized, and are moved into the gap left by removed items.
+                unsafe {
+                    let ptr = self.list.ptr.as_ptr();
+                    copy(ptr.add(self.read), ptr.add(self.write), self.len - self.read);
+                }
+
+                self.list.len = self.write + (self.len - self.read);
+            }
+        }
+
+        let len = self.len;
+        if len <= 1 { return; }
+
+        self.len = 0;
+
+        let mut guard = DedupGuard { list: self, len, read: 1, write: 1 };
+
+        while guard.read < guard.len {
+            /*
+                SAFETY:
+                - `write <= read < len`, so the current item and the last retained item at `write - 1` are initialized and distinct.
+                - The counters are updated before dropping a removed item, so the guard never touches it again.
+                - Retained items are moved into `write`, which is either vacated or the item's own slot.
+            */
+            unsafe {
+                let ptr = guard.list.ptr.as_ptr();
+                let current = ptr.add(guard.read);
+
+                if same_bucket(&mut *current, &mut *ptr.add(guard.write - 1)) {
+                    guard.read += 1;
+                    drop_in_place(current);
+                    continue;
+                }
+
+                if guard.read != guard.write {
+                    copy_nonoverlapping(current, ptr.add(guard.write), 1);
+                }
+
+                guard.read += 1;
+                guard.write += 1;
+            }
+        }
+    }
+
+    /// Removes consecutive items of the [`List`] that resolve to the same `key`, keeping the first of each run.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![10, 11, 20, 21, 22, 10];
+    /// list.dedup_by_key(|x| *x / 10);
+    /// 
+    /// assert_eq!(list, list![10, 20, 10]);
+    /// ```
+    #[inline]
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Sorts the [`List`] in place with the `compare` function, preserving the order of equal items.
+    /// Uses a stable merge sort over the [`List`]'s contiguous buffer, time complexity is `O(n * log(n))`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![3, 1, 2];
+    /// list.sort_by(|a, b| b.cmp(a));
+    /// 
+    /// assert_eq!(list, list![3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {
+        self.as_mut_slice().sort_by(compare);
+    }
+
+    /// Sorts the [`List`] in place by the `key` of each item, preserving the order of items with equal keys.
+    /// Uses a stable merge sort over the [`List`]'s contiguous buffer, time complexity is `O(n * log(n))`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![-3, 1, -2];
+    /// list.sort_by_key(|x| x.abs());
+    /// 
+    /// assert_eq!(list, list![1, -2, -3]);
+    /// ```
+    #[inline]
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, key: F) {
+        self.as_mut_slice().sort_by_key(key);
+    }
+
+    /// Sorts the [`List`] in place with the `compare` function, without preserving the order of equal items.
+    /// Uses a pattern-defeating quicksort that never allocates, time complexity is `O(n * log(n))`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![3, 1, 2];
+    /// list.sort_unstable_by(|a, b| b.cmp(a));
+    /// 
+    /// assert_eq!(list, list![3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn sort_unstable_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {
+        self.as_mut_slice().sort_unstable_by(compare);
+    }
+
+    /// Sorts the [`List`] in place by the `key` of each item, without preserving the order of items with equal keys.
+    /// Uses a pattern-defeating quicksort that never allocates, time complexity is `O(n * log(n))`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![-3, 1, -2];
+    /// list.sort_unstable_by_key(|x| x.abs());
+    /// 
+    /// assert_eq!(list, list![1, -2, -3]);
+    /// ```
+    #[inline]
+    pub fn sort_unstable_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, key: F) {
+        self.as_mut_slice().sort_unstable_by_key(key);
+    }
+
+    /// Binary searches the sorted [`List`] with the `compare` function, which returns the [`Ordering`] of an item relative to the target.
+    /// Returns `Ok` with the index of a match, or `Err` with the index where a matching item could be inserted while keeping the [`List`] sorted.
+    /// Time complexity is `O(log(n))`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 3, 5];
+    /// 
+    /// assert_eq!(list.binary_search_by(|x| x.cmp(&3)), Ok(1));
+    /// assert_eq!(list.binary_search_by(|x| x.cmp(&4)), Err(2));
+    /// ```
+    #[inline]
+    pub fn binary_search_by<F: FnMut(&T) -> Ordering>(&self, compare: F) -> Result<usize, usize> {
+        return self.as_slice().binary_search_by(compare);
+    }
+
+    /// Binary searches the [`List`], sorted by the `key` of each item, for the given `target` key.
+    /// Returns `Ok` with the index of a match, or `Err` with the index where a matching item could be inserted while keeping the [`List`] sorted.
+    /// Time complexity is `O(log(n))`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![(1, 'a'), (3, 'b'), (5, 'c')];
+    /// 
+    /// assert_eq!(list.binary_search_by_key(&5, |x| x.0), Ok(2));
+    /// assert_eq!(list.binary_search_by_key(&0, |x| x.0), Err(0));
+    /// ```
+    #[inline]
+    pub fn binary_search_by_key<K: Ord, F: FnMut(&T) -> K>(&self, target: &K, key: F) -> Result<usize, usize> {
+        return self.as_slice().binary_search_by_key(target, key);
+    }
+
+    /// Returns the index of the first item for which `predicate` returns `false`, assuming the [`List`] is partitioned
+    /// such that all items matching `predicate` come before all items that do not. Time complexity is `O(log(n))`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 4, 5];
+    /// let index = list.partition_point(|&x| x < 3);
+    /// 
+    /// list.insert(index, 3);
+    /// assert_eq!(list, list![1, 2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn partition_point<F: FnMut(&T) -> bool>(&self, predicate: F) -> usize {
+        return self.as_slice().partition_point(predicate);
+    }
+
+    /// Reverses the order of the items within the [`List`] in place.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3];
+    /// list.reverse();
+    /// 
+    /// assert_eq!(list, list![3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.as_mut_slice().reverse();
+    }
+
+    /// Rotates the [`List`] in place by `k` positions to the left, such that the item at `k` becomes the first item.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Panics
+    /// Panics if `k` is greater than the [`List`]'s `len`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3, 4, 5];
+    /// list.rotate_left(2);
+    /// 
+    /// assert_eq!(list, list![3, 4, 5, 1, 2]);
+    /// ```
+    #[inline]
+    pub fn rotate_left(&mut self, k: usize) {
+        if k > self.len { panic!("Rotation '{}' out of bounds.", k); }
+        self.as_mut_slice().rotate_left(k);
+    }
+
+    /// Rotates the [`List`] in place by `k` positions to the right, such that the last `k` items become the first items.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Panics
+    /// Panics if `k` is greater than the [`List`]'s `len`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3, 4, 5];
+    /// list.rotate_right(2);
+    /// 
+    /// assert_eq!(list, list![4, 5, 1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn rotate_right(&mut self, k: usize) {
+        if k > self.len { panic!("Rotation '{}' out of bounds.", k); }
+        self.as_mut_slice().rotate_right(k);
+    }
+
+    /// Splits the [`List`] at `at`, returning a newly allocated [`List`] holding the items from `at` onwards.
+    /// The [`List`] keeps the items before `at` along with its `capacity`, items are moved and never cloned.
+    /// 
+    /// ## Panics
+    /// Panics if `at` is greater than the [`List`]'s `len`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3, 4];
+    /// let tail = list.split_off(1);
+    /// 
+    /// assert_eq!(list, list![1]);
+    /// assert_eq!(tail, list![2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        if at > self.len { panic!("Index '{}' out of bounds.", at); }
+
+        let tail_len = self.len - at;
+
+        let mut tail = List::with_capacity(tail_len);
+        tail.growth = self.growth;
+
+        /*
+            SAFETY:
+            - `at..len` is within the initialized region, and `tail` has the `capacity` to hold all of its items.
+            - The `len` of the list is shrunk, such that the moved items are only owned by `tail`.
+        */
+        unsafe {
+            copy_nonoverlapping(self.ptr.as_ptr().add(at), tail.ptr.as_ptr(), tail_len);
+
+            self.len = at;
+            tail.len = tail_len;
+        }
+
+        return tail;
+    }
+
+    /// Consumes the [`List`], splitting it at `index` into two owned [`List`]s.
+    /// The first holds the items before `index` and reuses the original buffer, the second holds the rest.
+    /// To borrow both halves instead, use [`List::as_slice()`] followed by `split_at()`.
+    /// 
+    /// ## Panics
+    /// Panics if `index` is greater than the [`List`]'s `len`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let (front, back) = list![1, 2, 3, 4].split_at(1);
+    /// 
+    /// assert_eq!(front, list![1]);
+    /// assert_eq!(back, list![2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn split_at(mut self, index: usize) -> (Self, Self) {
+        let back = self.split_off(index);
+        return (self, back);
+    }
+
+    /// Overwrites every item of the [`List`] with values returned by calling `f`, dropping the previous items.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![0, 0, 0];
+    /// let mut count = 0;
+    /// 
+    /// list.fill_with(|| { count += 1; return count; });
+    /// assert_eq!(list, list![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn fill_with<F: FnMut() -> T>(&mut self, f: F) {
+        self.as_mut_slice().fill_with(f);
+    }
+
+    /// Resizes the [`List`] in place so that its `len` equals `new_len`.
+    /// Shrinking truncates the [`List`], growing appends values returned by calling `f`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2];
+    /// let mut count = 2;
+    /// 
+    /// list.resize_with(4, || { count += 1; return count; });
+    /// assert_eq!(list, list![1, 2, 3, 4]);
+    /// 
+    /// list.resize_with(1, || 0);
+    /// assert_eq!(list, list![1]);
+    /// ```
+    #[inline]
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, mut f: F) {
+        if new_len <= self.len { return self.truncate(new_len); }
+
+        self.reserve(new_len - self.len);
+        while self.len < new_len { self.push(f()); }
+    }
+
+    /// Returns an [`Iterator`] over all overlapping windows of `size` contiguous items within the [`List`].
+    /// Yields nothing if the [`List`] is shorter than `size`.
+    /// 
+    /// ## Panics
+    /// Panics if `size` is `0`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3, 4];
+    /// let sums: List<i32> = list.windows(2).map(|window| window.iter().sum()).collect();
+    /// 
+    /// assert_eq!(sums, list![3, 5, 7]);
+    /// ```
+    #[inline]
+    pub fn windows(&self, size: usize) -> slice::Windows<'_, T> {
+        assert!(size != 0, "Window size must be non-zero.");
+        return self.as_slice().windows(size);
+    }
+
+    /// Returns an [`Iterator`] over non-overlapping slices of `chunk_size` items within the [`List`].
+    /// The last chunk will be shorter if the `len` of the [`List`] is not evenly divisible by `chunk_size`.
+    /// 
+    /// ## Panics
+    /// Panics if `chunk_size` is `0`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3, 4, 5];
+    /// let mut chunks = list.chunks(2);
+    /// 
+    /// assert_eq!(chunks.next(), Some(&[1, 2][..]));
+    /// assert_eq!(chunks.next(), Some(&[3, 4][..]));
+    /// assert_eq!(chunks.next(), Some(&[5][..]));
+    /// ```
+    #[inline]
+    pub fn chunks(&self, chunk_size: usize) -> slice::Chunks<'_, T> {
+        assert!(chunk_size != 0, "Chunk size must be non-zero.");
+        return self.as_slice().chunks(chunk_size);
+    }
+
+    /// Returns an [`Iterator`] over non-overlapping mutable slices of `chunk_size` items within the [`List`].
+    /// The last chunk will be shorter if the `len` of the [`List`] is not evenly divisible by `chunk_size`.
+    /// 
+    /// ## Panics
+    /// Panics if `chunk_size` is `0`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3, 4, 5];
+    /// for chunk in list.chunks_mut(2) { chunk.reverse(); }
+    /// 
+    /// assert_eq!(list, list![2, 1, 4, 3, 5]);
+    /// ```
+    #[inline]
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> slice::ChunksMut<'_, T> {
+        assert!(chunk_size != 0, "Chunk size must be non-zero.");
+        return self.as_mut_slice().chunks_mut(chunk_size);
+    }
+
+    /// Returns an [`Iterator`] over non-overlapping slices of exactly `chunk_size` items within the [`List`].
+    /// Trailing items that do not fill a chunk are skipped, and can be retrieved with `remainder()` on the iterator.
+    /// 
+    /// ## Panics
+    /// Panics if `chunk_size` is `0`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3, 4, 5];
+    /// let mut chunks = list.chunks_exact(2);
+    /// 
+    /// assert_eq!(chunks.next(), Some(&[1, 2][..]));
+    /// assert_eq!(chunks.next(), Some(&[3, 4][..]));
+    /// assert_eq!(chunks.next(), None);
+    /// assert_eq!(chunks.remainder(), &[5]);
+    /// ```
+    #[inline]
+    pub fn chunks_exact(&self, chunk_size: usize) -> slice::ChunksExact<'_, T> {
+        assert!(chunk_size != 0, "Chunk size must be non-zero.");
+        return self.as_slice().chunks_exact(chunk_size);
+    }
+
+    /// Returns `true` if every pair of consecutive items within the [`List`] satisfies `compare`,
+    /// which should return whether its first argument may be ordered before its second. Time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![3, 2, 2, 1];
+    /// 
+    /// assert!(list.is_sorted_by(|a, b| a >= b));
+    /// assert!(!list.is_sorted_by(|a, b| a <= b));
+    /// ```
+    #[inline]
+    pub fn is_sorted_by<F: FnMut(&T, &T) -> bool>(&self, mut compare: F) -> bool {
+        return self.as_slice().windows(2).all(|pair| compare(&pair[0], &pair[1]));
+    }
+
+    /// Returns `true` if the `key` of each item within the [`List`] is sorted in ascending order.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![-1, 2, -3];
+    /// 
+    /// assert!(list.is_sorted_by_key(|x| x.abs()));
+    /// assert!(!list.is_sorted_by_key(|x| *x));
+    /// ```
+    #[inline]
+    pub fn is_sorted_by_key<K: PartialOrd, F: FnMut(&T) -> K>(&self, mut key: F) -> bool {
+        return self.is_sorted_by(|a, b| key(a) <= key(b));
+    }
+
+    /// Consumes the [`List`], returning a new [`List`] holding the result of calling `f` on each item.
+    /// The output is allocated up front with the `len` of the [`List`].
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3].map(|x| x * 2);
+    /// assert_eq!(list, list![2, 4, 6]);
+    /// ```
+    #[inline]
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> List<U> {
+        return self.into_iter().map(f).collect();
+    }
+
+    /// Fallible version of [`List::map()`], returning the first `Err` produced by `f`.
+    /// Upon an `Err`, the already mapped values and the remaining items are dropped.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list!["1", "2", "3"].try_map(|x| x.parse::<i32>());
+    /// assert_eq!(list, Ok(list![1, 2, 3]));
+    /// 
+    /// let list = list!["1", "a", "3"].try_map(|x| x.parse::<i32>());
+    /// assert!(list.is_err());
+    /// ```
+    #[inline]
+    pub fn try_map<U, E, F: FnMut(T) -> Result<U, E>>(self, mut f: F) -> Result<List<U>, E> {
+        let mut list = List::with_capacity(self.len);
+        for value in self { list.push(f(value)?); }
+
+        return Ok(list);
+    }
+
+    /// Consumes the [`List`], returning it with only the items for which `predicate` returns `true`.
+    /// Items are filtered in place, reusing the [`List`]'s buffer.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3, 4].filter(|x| x % 2 == 0);
+    /// assert_eq!(list, list![2, 4]);
+    /// ```
+    #[inline]
+    pub fn filter<F: FnMut(&T) -> bool>(mut self, predicate: F) -> Self {
+        self.retain(predicate);
+        return self;
+    }
+
+    /// Consumes the [`List`], returning a new [`List`] holding every `Some` value returned by calling `f` on each item.
+    /// The output is allocated up front with the `len` of the [`List`], which is the most it can hold.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list!["1", "a", "3"].filter_map(|x| x.parse::<i32>().ok());
+    /// assert_eq!(list, list![1, 3]);
+    /// ```
+    #[inline]
+    pub fn filter_map<U, F: FnMut(T) -> Option<U>>(self, mut f: F) -> List<U> {
+        let mut list = List::with_capacity(self.len);
+
+        for value in self {
+            if let Some(value) = f(value) { list.push(value); }
+        }
+
+        return list;
+    }
+
+    /// Consumes the [`List`], folding every item into an accumulator by repeatedly calling `f`, starting from `init`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let sum = list![1, 2, 3].fold(0, |sum, x| sum + x);
+    /// assert_eq!(sum, 6);
+    /// ```
+    #[inline]
+    pub fn fold<B, F: FnMut(B, T) -> B>(self, init: B, f: F) -> B {
+        return self.into_iter().fold(init, f);
+    }
+
+    /// Consumes the [`List`], splitting it into the items for which `predicate` returns `true` and those for which it returns `false`.
+    /// The relative order of the items is preserved within both [`List`]s.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let (evens, odds) = list![1, 2, 3, 4].partition(|x| x % 2 == 0);
+    /// 
+    /// assert_eq!(evens, list![2, 4]);
+    /// assert_eq!(odds, list![1, 3]);
+    /// ```
+    #[inline]
+    pub fn partition<P: FnMut(&T) -> bool>(self, mut predicate: P) -> (Self, Self) {
+        let mut matching = List::new();
+        let mut rest = List::new();
+
+        for value in self {
+            if predicate(&value) { matching.push(value); }
+            else { rest.push(value); }
+        }
+
+        return (matching, rest);
+    }
+
+    /// Consumes both [`List`]s, returning a new [`List`] that alternates between their items, starting with the [`List`].
+    /// The remaining items of the longer [`List`] are appended at the end.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 3, 5, 7].interleave(list![2, 4]);
+    /// assert_eq!(list, list![1, 2, 3, 4, 5, 7]);
+    /// ```
+    #[inline]
+    pub fn interleave(self, other: Self) -> Self {
+        let mut list = List::with_capacity(self.len + other.len);
+
+        let mut a = self.into_iter();
+        let mut b = other.into_iter();
+
+        loop {
+            match (a.next(), b.next()) {
+                (Some(x), Some(y)) => { list.push(x); list.push(y); },
+                (Some(x), None) => { list.push(x); list.extend(a); break; },
+                (None, Some(y)) => { list.push(y); list.extend(b); break; },
+                (None, None) => break,
+            }
+        }
+
+        return list;
+    }
+
+    /// Consumes both [`List`]s, returning a new [`List`] of pairs holding their items in order.
+    /// Stops at the shorter of the two, dropping any excess items of the longer one.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3].zip(list!['a', 'b']);
+    /// assert_eq!(list, list![(1, 'a'), (2, 'b')]);
+    /// ```
+    #[inline]
+    pub fn zip<U>(self, other: List<U>) -> List<(T, U)> {
+        return self.into_iter().zip(other).collect();
+    }
+
+    /// Consumes both [`List`]s, returning a new [`List`] holding the result of calling `f` on each pair of items.
+    /// Stops at the shorter of the two, dropping any excess items of the longer one.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3].zip_with(list![10, 20, 30], |a, b| a + b);
+    /// assert_eq!(list, list![11, 22, 33]);
+    /// ```
+    #[inline]
+    pub fn zip_with<U, V, F: FnMut(T, U) -> V>(self, other: List<U>, mut f: F) -> List<V> {
+        return self.into_iter().zip(other).map(|(a, b)| f(a, b)).collect();
+    }
+
+    /// Returns an [`Iterator`] over the maximal runs of items within the [`List`], such that
+    /// `predicate` returns `true` for every pair of consecutive items within a run.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 1, 2, 3, 3, 3];
+    /// let mut runs = list.chunk_by(|a, b| a == b);
+    /// 
+    /// assert_eq!(runs.next(), Some(&[1, 1][..]));
+    /// assert_eq!(runs.next(), Some(&[2][..]));
+    /// assert_eq!(runs.next(), Some(&[3, 3, 3][..]));
+    /// assert_eq!(runs.next(), None);
+    /// ```
+    #[inline]
+    pub fn chunk_by<F: FnMut(&T, &T) -> bool>(&self, predicate: F) -> slice::ChunkBy<'_, T, F> {
+        return self.as_slice().chunk_by(predicate);
+    }
+
+    /// Consumes the [`List`], dividing it into `n` [`List`]s whose lengths differ by at most one, preserving the order of the items.
+    /// The first `len % n` [`List`]s hold one extra item, if `n` is greater than `len` the trailing [`List`]s are empty.
+    /// 
+    /// ## Panics
+    /// Panics if `n` is `0`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let parts = list![1, 2, 3, 4, 5].split_into(3);
+    /// assert_eq!(parts, list![list![1, 2], list![3, 4], list![5]]);
+    /// ```
+    #[inline]
+    pub fn split_into(self, n: usize) -> List<List<T>> {
+        assert!(n != 0, "Number of parts must be non-zero.");
+
+        let (size, remainder) = (self.len / n, self.len % n);
+
+        let mut iter = self.into_iter();
+        let mut parts = List::with_capacity(n);
+
+        for index in 0..n {
+            let len = if index < remainder { size + 1 } else { size };
+            parts.push(iter.by_ref().take(len).collect());
+        }
+
+        return parts;
+    }
+
+    /// Consumes the [`List`], returning an [`Iterator`] over owned [`List`]s of `chunk_size` items.
+    /// The last chunk will be shorter if the `len` of the [`List`] is not evenly divisible by `chunk_size`.
+    /// Items are moved in bulk into each chunk, no cloning takes place.
+    /// 
+    /// ## Panics
+    /// Panics if `chunk_size` is `0`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3, 4, 5];
+    /// let mut chunks = list.into_chunks(2);
+    /// 
+    /// assert_eq!(chunks.next(), Some(list![1, 2]));
+    /// assert_eq!(chunks.next(), Some(list![3, 4]));
+    /// assert_eq!(chunks.next(), Some(list![5]));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    #[inline]
+    pub fn into_chunks(self, chunk_size: usize) -> IntoChunks<T> {
+        assert!(chunk_size != 0, "Chunk size must be non-zero.");
+
+        return IntoChunks {
+            iter: self.into_iter(),
+            chunk_size,
+        };
+    }
+
+    /// Returns a slice containing every item within the [`List`].
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3];
+    /// assert_eq!(list.as_slice(), &[1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: The first `len` items of the buffer are initialized, `ptr` is non-null and aligned even when dangling.
+        return unsafe { from_raw_parts(self.ptr.as_ptr(), self.len) };
+    }
+
+    /// Returns a mutable slice containing every item within the [`List`].
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3];
+    /// list.as_mut_slice()[0] = 4;
+    /// 
+    /// assert_eq!(list.as_slice(), &[4, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: The first `len` items of the buffer are initialized, `ptr` is non-null and aligned even when dangling.
+        return unsafe { from_raw_parts_mut(self.ptr.as_ptr(), self.len) };
+    }
+
+    /// Returns a raw pointer to the buffer of the [`List`], which is valid for reads of `len` items.
+    /// The pointer is dangling, but non-null and aligned, when the [`List`] has not allocated.
+    /// It is invalidated by any operation that reallocates the [`List`], and must not be written through.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3];
+    /// let ptr = list.as_ptr();
+    /// 
+    /// assert_eq!(unsafe { *ptr.add(2) }, 3);
+    /// ```
+    #[inline]
+    pub const fn as_ptr(&self) -> *const T {
+        return self.ptr.as_ptr();
+    }
+
+    /// Returns a mutable raw pointer to the buffer of the [`List`], which is valid for reads and writes of `len` items.
+    /// The pointer is dangling, but non-null and aligned, when the [`List`] has not allocated.
+    /// It is invalidated by any operation that reallocates the [`List`].
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3];
+    /// let ptr = list.as_mut_ptr();
+    /// 
+    /// unsafe { *ptr.add(2) = 4; }
+    /// assert_eq!(list, list![1, 2, 4]);
+    /// ```
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        return self.ptr.as_ptr();
+    }
+
+    /// Converts the [`List`] into a [`Box<[T]>`], shrinking its buffer down to its `len`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let slice = list![1, 2, 3].into_boxed_slice();
+    /// assert_eq!(&*slice, &[1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        return Vec::from(self).into_boxed_slice();
+    }
+
+    /// Converts the [`List`] into a reference-counted [`Rc<[T]>`].
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let slice = list![1, 2, 3].into_rc_slice();
+    /// assert_eq!(&*slice, &[1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn into_rc_slice(self) -> Rc<[T]> {
+        return Rc::from(Vec::from(self));
+    }
+
+    /// Converts the [`List`] into an atomically reference-counted [`Arc<[T]>`].
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let slice = list![1, 2, 3].into_arc_slice();
+    /// assert_eq!(&*slice, &[1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn into_arc_slice(self) -> Arc<[T]> {
+        return Arc::from(Vec::from(self));
+    }
+
+    /// Consumes and leaks the [`List`], returning a mutable reference to its items.
+    /// The buffer is never deallocated, which is useful for data that lives for the rest of the program.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let slice: &'static mut [i32] = list![1, 2, 3].leak();
+    /// slice[0] = 4;
+    /// 
+    /// assert_eq!(slice, &[4, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn leak<'a>(self) -> &'a mut [T] {
+        let list = ManuallyDrop::new(self);
+
+        // SAFETY: The buffer is never deallocated, and the first `len` items are initialized.
+        return unsafe { from_raw_parts_mut(list.ptr.as_ptr(), list.len) };
+    }
+
+    /// Decomposes the [`List`] into its raw components, `(ptr, len, capacity)`, without dropping any items.
+    /// The caller becomes responsible for the buffer, which can be turned back into a [`List`] with [`List::from_raw_parts()`].
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let (ptr, len, capacity) = list![1, 2, 3].into_raw_parts();
+    /// let list = unsafe { List::from_raw_parts(ptr, len, capacity) };
+    /// 
+    /// assert_eq!(list, list![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn into_raw_parts(self) -> (*mut T, usize, usize) {
+        let list = ManuallyDrop::new(self);
+        return (list.ptr.as_ptr(), list.len, list.capacity);
+    }
+
+    /// Creates a [`List`] directly from its raw components, the inverse of [`List::into_raw_parts()`].
+    /// The [`List`] takes ownership of the buffer and uses the default [`GrowthPolicy`].
+    /// 
+    /// ## Safety
+    /// - `ptr` must be non-null and aligned, and have been allocated by the global allocator for exactly `capacity` items of `T`,
+    ///   unless `capacity` is `0` or `T` is zero-sized.
+    /// - For zero-sized types `capacity` must be `usize::MAX`.
+    /// - The first `len` items of the buffer must be initialized, and `len` must not exceed `capacity`.
+    /// - The buffer must not be used through any other owner afterwards.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut vec = vec![1, 2, 3];
+    /// let (ptr, len, capacity) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+    /// core::mem::forget(vec);
+    /// 
+    /// let list = unsafe { List::from_raw_parts(ptr, len, capacity) };
+    /// assert_eq!(list, list![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub unsafe fn from_raw_parts(ptr: *mut T, len: usize, capacity: usize) -> Self {
+        return Self {
+            ptr: NonNull::new_unchecked(ptr),
+            capacity,
+            len,
+            growth: GrowthPolicy::default(),
+        };
+    }
+
+    /// Returns an [`Iterator`] over references to the items within the [`List`].
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3];
+    /// let mut iter = list.iter();
+    /// 
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        return self.as_slice().iter();
+    }
+
+    /// Returns an [`Iterator`] over mutable references to the items within the [`List`].
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3];
+    /// 
+    /// for item in list.iter_mut() {
+    ///     *item *= 2;
+    /// }
+    /// 
+    /// assert_eq!(list, list![2, 4, 6]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        return self.as_mut_slice().iter_mut();
+    }
+
+    /// Returns a reference to the item at the given `index`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3];
+    /// 
+    /// assert_eq!(list.get(0), Some(&1));
+    /// ```
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len { return None; }
+
+        return unsafe {
+            Some(&*self.ptr.as_ptr().add(index))
+        };
+    }
+
+    /// Returns a mutable reference to the item at the given `index`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3];
+    ///
+    /// *list.get_mut(0).unwrap() = 4;
+    /// assert_eq!(list.get(0), Some(&4));
+    /// ```
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len { return None; }
+
+        return unsafe {
+            Some(&mut *self.ptr.as_ptr().add(index))
+        };
+    }
+
+    /// Returns a reference to the item at the given `index`, without checking that it is in bounds.
+    /// 
+    /// ## Safety
+    /// `index` must be less than the [`List`]'s `len`, otherwise the behavior is undefined.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3];
+    /// 
+    /// assert_eq!(unsafe { list.get_unchecked(1) }, &2);
+    /// ```
+    #[inline]
+    pub unsafe fn get_unchecked(&self, index: usize) -> &T {
+        debug_assert!(index < self.len);
+        return &*self.ptr.as_ptr().add(index);
+    }
+
+    /// Returns a mutable reference to the item at the given `index`, without checking that it is in bounds.
+    /// 
+    /// ## Safety
+    /// `index` must be less than the [`List`]'s `len`, otherwise the behavior is undefined.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3];
+    /// 
+    /// unsafe { *list.get_unchecked_mut(1) = 4; }
+    /// assert_eq!(list, list![1, 4, 3]);
+    /// ```
+    #[inline]
+    pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
+        debug_assert!(index < self.len);
+        return &mut *self.ptr.as_ptr().add(index);
+    }
+
+    /// Returns mutable references to the items at each of the given `indices` at once.
+    /// Returns a [`GetManyMutError`] if any index is out of bounds, or if the same index is requested more than once.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3];
+    /// 
+    /// let [a, b] = list.get_many_mut([0, 2]).unwrap();
+    /// core::mem::swap(a, b);
+    /// 
+    /// assert_eq!(list, list![3, 2, 1]);
+    /// assert!(list.get_many_mut([1, 1]).is_err());
+    /// ```
+    #[inline]
+    pub fn get_many_mut<const N: usize>(&mut self, indices: [usize; N]) -> Result<[&mut T; N], GetManyMutError> {
+        for (position, &index) in indices.iter().enumerate() {
+            if index >= self.len { return Err(GetManyMutError::IndexOutOfBounds(index)); }
+
+            if indices[..position].contains(&index) {
+                return Err(GetManyMutError::OverlappingIndices(index));
+            }
+        }
+
+        let ptr = self.ptr.as_ptr();
+
+        // SAFETY: Every index is in bounds and distinct, so the returned references never alias.
+        return Ok(indices.map(|index| unsafe { &mut *ptr.add(index) }));
+    }
+
+    /// Returns the index of the first item within the [`List`] for which `predicate` returns `true`.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3, 4];
+    /// 
+    /// assert_eq!(list.position(|x| x % 2 == 0), Some(1));
+    /// assert_eq!(list.position(|x| *x > 4), None);
+    /// ```
+    #[inline]
+    pub fn position<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<usize> {
+        return self.iter().position(predicate);
+    }
+
+    /// Returns a reference to the first item within the [`List`] for which `predicate` returns `true`.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3, 4];
+    /// 
+    /// assert_eq!(list.find(|x| x % 2 == 0), Some(&2));
+    /// assert_eq!(list.find(|x| *x > 4), None);
+    /// ```
+    #[inline]
+    pub fn find<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<&T> {
+        return self.iter().find(|x| predicate(x));
+    }
+
+    /// Returns a reference to the last item within the [`List`] for which `predicate` returns `true`.
+    /// Time complexity is `O(n)`, searching from the `back`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3, 4];
+    /// 
+    /// assert_eq!(list.rfind(|x| x % 2 == 0), Some(&4));
+    /// assert_eq!(list.rfind(|x| *x > 4), None);
+    /// ```
+    #[inline]
+    pub fn rfind<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<&T> {
+        return self.iter().rev().find(|x| predicate(x));
+    }
+
+    /// Returns a reference to the item at the `front` of the list.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![2, 4, 6];
+    /// assert_eq!(list.front(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        return self.get(0);
+    }
+
+    /// Returns a reference to the item at the `back` of the list.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![2, 4, 6];
+    /// assert_eq!(list.back(), Some(&6));
+    /// ```
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        return self.get(self.len.checked_sub(1)?);
+    }
+
+    /// Returns a mutable reference to the item at the `front` of the list.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![2, 4, 6];
+    /// assert_eq!(list.front_mut(), Some(&mut 2));
+    /// ```
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        return self.get_mut(0);
+    }
+
+    /// Returns a mutable reference to the item at the `back` of the list.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![2, 4, 6];
+    /// assert_eq!(list.back_mut(), Some(&mut 6));
+    /// ```
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        return self.get_mut(self.len.checked_sub(1)?);
+    }
+
+    /// Returns a reference to the first item of the list, an alias of [`List::front()`].
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![2, 4, 6];
+    /// assert_eq!(list.first(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        return self.front();
+    }
+
+    /// Returns a reference to the last item of the list, an alias of [`List::back()`].
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![2, 4, 6];
+    /// assert_eq!(list.last(), Some(&6));
+    /// ```
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        return self.back();
+    }
+
+    /// Returns a mutable reference to the first item of the list, an alias of [`List::front_mut()`].
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![2, 4, 6];
+    /// assert_eq!(list.first_mut(), Some(&mut 2));
+    /// ```
+    #[inline]
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        return self.front_mut();
+    }
+
+    /// Returns a mutable reference to the last item of the list, an alias of [`List::back_mut()`].
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![2, 4, 6];
+    /// assert_eq!(list.last_mut(), Some(&mut 6));
+    /// ```
+    #[inline]
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        return self.back_mut();
+    }
+}
+
+
+impl<T: Clone> List<T> {
+    /// Overwrites every item of the [`List`] with clones of `value`, dropping the previous items.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2, 3];
+    /// list.fill(0);
+    /// 
+    /// assert_eq!(list, list![0, 0, 0]);
+    /// ```
+    #[inline]
+    pub fn fill(&mut self, value: T) {
+        self.as_mut_slice().fill(value);
+    }
+
+    /// Resizes the [`List`] in place so that its `len` equals `new_len`.
+    /// Shrinking truncates the [`List`], growing appends clones of `value`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2];
+    /// 
+    /// list.resize(4, 0);
+    /// assert_eq!(list, list![1, 2, 0, 0]);
+    /// 
+    /// list.resize(1, 0);
+    /// assert_eq!(list, list![1]);
+    /// ```
+    #[inline]
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        if new_len <= self.len { return self.truncate(new_len); }
+
+        self.reserve(new_len - self.len);
+        while self.len + 1 < new_len { self.push(value.clone()); }
+
+        self.push(value);
+    }
+
+    /// Appends clones of every item within `slice` to the end of the [`List`], reserving `capacity` for all of them up front.
+    /// For [`Copy`] types, [`List::extend_from_copy_slice()`] copies the items in bulk instead.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 2];
+    /// list.extend_from_slice(&[3, 4]);
+    /// 
+    /// assert_eq!(list, list![1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn extend_from_slice(&mut self, slice: &[T]) {
+        self.reserve(slice.len());
+        for item in slice { self.push(item.clone()); }
+    }
+
+    /// Creates a new [`List`] holding the items of the [`List`] repeated `n` times, using a single allocation of `len * n` items.
+    /// 
+    /// ## Panics
+    /// Panics if `len * n` overflows `usize`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2];
+    /// assert_eq!(list.repeat(3), list![1, 2, 1, 2, 1, 2]);
+    /// ```
+    #[inline]
+    pub fn repeat(&self, n: usize) -> Self {
+        let capacity = self.len.checked_mul(n)
+            .unwrap_or_else(|| panic!("Capacity overflow."));
+
+        let mut list = List::with_capacity(capacity);
+        for _ in 0..n { list.extend_from_slice(self); }
+
+        return list;
+    }
+
+    /// Appends clones of the items within `range` of the [`List`] to its end, reserving `capacity` for all of them up front.
+    /// 
+    /// ## Panics
+    /// Panics if `range` is out of bounds, or if it starts after it ends.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list!['a', 'b', 'c'];
+    /// list.extend_from_within(1..);
+    /// 
+    /// assert_eq!(list, list!['a', 'b', 'c', 'b', 'c']);
+    /// ```
+    #[inline]
+    pub fn extend_from_within<R: RangeBounds<usize>>(&mut self, range: R) {
+        let Range { start, end } = resolve_range(range, self.len);
+        self.reserve(end - start);
+
+        for index in start..end {
+            /*
+                SAFETY:
+                - `index` lies within the initialized region, which is never moved as enough `capacity` was reserved.
+                - Each clone is written past the current `len`, so it never overlaps with the source items.
+                - The `len` is increased after every write, so a panicking `clone()` leaves the list consistent.
+            */
+            unsafe {
+                let ptr = self.ptr.as_ptr();
+                let value = (*ptr.add(index)).clone();
+
+                ptr.add(self.len).write(value);
+                self.len += 1;
+            }
+        }
+    }
+}
+
+
+impl<T: Copy> List<T> {
+    /// Appends every item within `slice` to the end of the [`List`] using a single bulk copy.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = List::from("Hello");
+    /// list.extend_from_copy_slice(b", World!");
+    /// 
+    /// assert_eq!(list, List::from("Hello, World!"));
+    /// ```
+    #[inline]
+    pub fn extend_from_copy_slice(&mut self, slice: &[T]) {
+        self.reserve(slice.len());
+
+        /*
+            SAFETY:
+            - Enough `capacity` was reserved for the items, which are written into the uninitialized region.
+            - `slice` cannot alias the spare capacity, as that would require a borrow of the list.
+            - `T: Copy`, so the copied items never need to be dropped from `slice`.
+        */
+        unsafe {
+            copy_nonoverlapping(slice.as_ptr(), self.ptr.as_ptr().add(self.len), slice.len());
+        }
+
+        self.len += slice.len();
+    }
+}
+
+
+impl<T: Ord> List<T> {
+    /// Sorts the [`List`] in ascending order, preserving the order of equal items.
+    /// Uses a stable merge sort over the [`List`]'s contiguous buffer, time complexity is `O(n * log(n))`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![3, 1, 2];
+    /// list.sort();
+    /// 
+    /// assert_eq!(list, list![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn sort(&mut self) {
+        self.as_mut_slice().sort();
+    }
+
+    /// Sorts the [`List`] in ascending order, without preserving the order of equal items.
+    /// Uses a pattern-defeating quicksort that never allocates, time complexity is `O(n * log(n))`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![3, 1, 2];
+    /// list.sort_unstable();
+    /// 
+    /// assert_eq!(list, list![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn sort_unstable(&mut self) {
+        self.as_mut_slice().sort_unstable();
+    }
+
+    /// Binary searches the sorted [`List`] for the given `value`.
+    /// Returns `Ok` with the index of a match, or `Err` with the index where `value` could be inserted while keeping the [`List`] sorted.
+    /// Time complexity is `O(log(n))`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 3, 5];
+    /// assert_eq!(list.binary_search(&3), Ok(1));
+    /// 
+    /// let index = list.binary_search(&4).unwrap_or_else(|index| index);
+    /// list.insert(index, 4);
+    /// 
+    /// assert_eq!(list, list![1, 3, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn binary_search(&self, value: &T) -> Result<usize, usize> {
+        return self.as_slice().binary_search(value);
+    }
+
+    /// Consumes two sorted [`List`]s, merging them into a single sorted [`List`].
+    /// Equal items from the [`List`] are placed before those from `other`, time complexity is `O(n + m)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 3, 5].merge_sorted(list![2, 3, 4]);
+    /// assert_eq!(list, list![1, 2, 3, 3, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn merge_sorted(self, other: Self) -> Self {
+        let mut list = List::with_capacity(self.len + other.len);
+
+        let mut a = self.into_iter().peekable();
+        let mut b = other.into_iter().peekable();
+
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let next = if y < x { b.next() } else { a.next() };
+            list.extend(next);
+        }
+
+        list.extend(a);
+        list.extend(b);
+
+        return list;
+    }
+}
+
+
+impl<T: Eq> List<T> {
+    /// Returns the index of the first occurrence of `needle` as a contiguous run of items within the [`List`].
+    /// Uses the Knuth–Morris–Pratt algorithm, such that time complexity is `O(n + m)` where `m` is the `len` of `needle`.
+    /// An empty `needle` is found at index `0`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 1, 2, 3];
+    /// 
+    /// assert_eq!(list.find_sublist(&[1, 2, 3]), Some(2));
+    /// assert_eq!(list.find_sublist(&[3, 1]), None);
+    /// ```
+    #[inline]
+    pub fn find_sublist(&self, needle: &[T]) -> Option<usize> {
+        let haystack = self.as_slice();
+
+        if needle.is_empty() { return Some(0); }
+        if needle.len() > haystack.len() { return None; }
+
+        // `prefix[i]` holds the length of the longest proper prefix of `needle[..=i]` that is also its suffix.
+        let mut prefix = List::with_capacity(needle.len());
+        prefix.push(0);
+
+        let mut matched = 0;
+
+        for item in &needle[1..] {
+            while matched > 0 && *item != needle[matched] { matched = prefix[matched - 1]; }
+            if *item == needle[matched] { matched += 1; }
+
+            prefix.push(matched);
+        }
+
+        matched = 0;
+
+        for (index, item) in haystack.iter().enumerate() {
+            while matched > 0 && *item != needle[matched] { matched = prefix[matched - 1]; }
+            if *item == needle[matched] { matched += 1; }
+
+            if matched == needle.len() { return Some(index + 1 - matched); }
+        }
+
+        return None;
+    }
+}
+
+
+impl<T: PartialOrd> List<T> {
+    /// Returns `true` if the items within the [`List`] are sorted in ascending order.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// assert!(list![1, 2, 2, 3].is_sorted());
+    /// assert!(!list![1, 3, 2].is_sorted());
+    /// ```
+    #[inline]
+    pub fn is_sorted(&self) -> bool {
+        return self.is_sorted_by(|a, b| a <= b);
+    }
+}
+
+
+impl<T: PartialEq> List<T> {
+    /// Removes consecutive equal items of the [`List`], keeping the first of each run.
+    /// If the [`List`] is sorted, this removes all duplicates. Time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = list![1, 1, 2, 3, 3, 3, 1];
+    /// list.dedup();
+    /// 
+    /// assert_eq!(list, list![1, 2, 3, 1]);
+    /// ```
+    #[inline]
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Consumes the [`List`], collapsing each run of consecutive equal items into a `(value, count)` pair.
+    /// The first item of each run is kept, this is the inverse of [`List::decode()`].
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list!['a', 'a', 'b', 'a', 'a', 'a'];
+    /// assert_eq!(list.dedup_with_counts(), list![('a', 2), ('b', 1), ('a', 3)]);
+    /// ```
+    #[inline]
+    pub fn dedup_with_counts(self) -> List<(T, usize)> {
+        let mut list: List<(T, usize)> = List::new();
+
+        for value in self {
+            match list.back_mut() {
+                Some((last, count)) if *last == value => *count += 1,
+                _ => list.push((value, 1)),
+            }
+        }
+
+        return list;
+    }
+
+    /// Returns `true` if the [`List`] contains an item equal to `value`.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3];
+    /// 
+    /// assert!(list.contains(&2));
+    /// assert!(!list.contains(&4));
+    /// ```
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        return self.as_slice().contains(value);
+    }
+
+    /// Returns `true` if the [`List`] begins with the items of `prefix`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3];
+    /// 
+    /// assert!(list.starts_with(&[1, 2]));
+    /// assert!(!list.starts_with(&[2]));
+    /// ```
+    #[inline]
+    pub fn starts_with(&self, prefix: &[T]) -> bool {
+        return self.as_slice().starts_with(prefix);
+    }
+
+    /// Returns `true` if the [`List`] ends with the items of `suffix`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![1, 2, 3];
+    /// 
+    /// assert!(list.ends_with(&[2, 3]));
+    /// assert!(!list.ends_with(&[2]));
+    /// ```
+    #[inline]
+    pub fn ends_with(&self, suffix: &[T]) -> bool {
+        return self.as_slice().ends_with(suffix);
+    }
+
+    /// Returns a slice of the items following `prefix`, or `None` if the [`List`] does not begin with `prefix`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = List::from("GET /index");
+    /// 
+    /// assert_eq!(list.strip_prefix(b"GET "), Some(&b"/index"[..]));
+    /// assert_eq!(list.strip_prefix(b"POST "), None);
+    /// ```
+    #[inline]
+    pub fn strip_prefix(&self, prefix: &[T]) -> Option<&[T]> {
+        return self.as_slice().strip_prefix(prefix);
+    }
+
+    /// Returns a slice of the items preceding `suffix`, or `None` if the [`List`] does not end with `suffix`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = List::from("data\r\n");
+    /// 
+    /// assert_eq!(list.strip_suffix(b"\r\n"), Some(&b"data"[..]));
+    /// assert_eq!(list.strip_suffix(b"\n\n"), None);
+    /// ```
+    #[inline]
+    pub fn strip_suffix(&self, suffix: &[T]) -> Option<&[T]> {
+        return self.as_slice().strip_suffix(suffix);
+    }
+}
+
+
+impl<A, B> List<(A, B)> {
+    /// Consumes the [`List`] of pairs, splitting it into two [`List`]s in a single pass.
+    /// Both outputs are allocated up front with the `len` of the [`List`].
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let (numbers, letters) = list![(1, 'a'), (2, 'b')].unzip();
+    /// 
+    /// assert_eq!(numbers, list![1, 2]);
+    /// assert_eq!(letters, list!['a', 'b']);
+    /// ```
+    #[inline]
+    pub fn unzip(self) -> (List<A>, List<B>) {
+        let mut a = List::with_capacity(self.len);
+        let mut b = List::with_capacity(self.len);
+
+        for (x, y) in self {
+            a.push(x);
+            b.push(y);
+        }
+
+        return (a, b);
+    }
+}
+
+
+impl<T: Clone> List<(T, usize)> {
+    /// Consumes a run-length encoded [`List`] of `(value, count)` pairs, expanding each into `count` clones of `value`.
+    /// This is the inverse of [`List::dedup_with_counts()`].
+    /// 
+    /// ## Panics
+    /// Panics if the total number of items overflows `usize`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![('a', 2), ('b', 1)];
+    /// assert_eq!(list.decode(), list!['a', 'a', 'b']);
+    /// ```
+    #[inline]
+    pub fn decode(self) -> List<T> {
+        let len = self.iter().try_fold(0usize, |len, (_, count)| len.checked_add(*count))
+            .unwrap_or_else(|| panic!("Capacity overflow."));
+
+        let mut list = List::with_capacity(len);
+
+        for (value, count) in self {
+            list.resize(list.len + count, value);
+        }
+
+        return list;
+    }
+}
+
+
+impl<T> List<List<T>> {
+    /// Consumes the [`List`] of [`List`]s, moving every inner item into a single [`List`].
+    /// The `capacity` is reserved from the summed lengths up front, items are moved and never cloned.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![list![1, 2], list![], list![3]];
+    /// assert_eq!(list.flatten(), list![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn flatten(self) -> List<T> {
+        let mut list = List::with_capacity(self.iter().map(List::len).sum());
+        for inner in self { list += inner; }
+
+        return list;
+    }
+}
+
+
+impl<T: Ord> List<List<T>> {
+    /// Consumes a [`List`] of `k` sorted [`List`]s, merging them into a single sorted [`List`].
+    /// Uses a [`BinaryHeap`] holding the front item of each [`List`], time complexity is `O(n * log(k))`.
+    /// Equal items are ordered by the position of the [`List`] they came from.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![list![1, 4], list![2, 5], list![3, 6]];
+    /// assert_eq!(list.kmerge(), list![1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[inline]
+    pub fn kmerge(self) -> List<T> {
+        let mut list = List::with_capacity(self.iter().map(List::len).sum());
+        let mut iters: List<IntoIter<T>> = self.map(List::into_iter);
+
+        let mut heap = BinaryHeap::with_capacity(iters.len());
+
+        for (index, iter) in iters.iter_mut().enumerate() {
+            if let Some(value) = iter.next() { heap.push(Reverse((value, index))); }
+        }
+
+        while let Some(Reverse((value, index))) = heap.pop() {
+            list.push(value);
+            if let Some(value) = iters[index].next() { heap.push(Reverse((value, index))); }
+        }
+
+        return list;
+    }
+}
+
+
+impl<T: Clone> List<List<T>> {
+    /// Flattens the [`List`] of [`List`]s into a single [`List`], cloning every item.
+    /// The total length is computed up front, such that the result is built with a single allocation.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![list![1, 2], list![3]];
+    /// assert_eq!(list.concat(), list![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn concat(&self) -> List<T> {
+        return List::from(self.as_slice().concat());
+    }
+
+    /// Flattens the [`List`] of [`List`]s into a single [`List`], cloning every item and placing `separator` between each.
+    /// The total length is computed up front, such that the result is built with a single allocation.
     /// 
     /// ## Example
     /// ```rust
-    /// let list = list![1, 2, 3];
-    /// 
-    /// assert_eq!(list.get(0), Some(&1));
+    /// let list = list![list![1, 2], list![3]];
+    /// assert_eq!(list.join(&[0]), list![1, 2, 0, 3]);
     /// ```
     #[inline]
-    pub fn get(&self, index: usize) -> Option<&T> {
-        if index >= self.len { return None; }
-
-        return unsafe {
-            Some(&*self.ptr.as_ptr().add(index))
-        };
+    pub fn join(&self, separator: &[T]) -> List<T> {
+        return List::from(self.as_slice().join(separator));
     }
+}
 
-    /// Returns a mutable reference to the item at the given `index`.
+
+impl<T: Clone> List<&[T]> {
+    /// Flattens the [`List`] of slices into a single [`List`], cloning every item.
+    /// The total length is computed up front, such that the result is built with a single allocation.
     /// 
     /// ## Example
     /// ```rust
-    /// let mut list = list![1, 2, 3];
-    ///
-    /// *list.get_mut(0).unwrap() = 4;
-    /// assert_eq!(list.get(0), Some(&4));
+    /// let list = list![&[1, 2][..], &[3][..]];
+    /// assert_eq!(list.concat(), list![1, 2, 3]);
     /// ```
     #[inline]
-    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        if index >= self.len { return None; }
+    pub fn concat(&self) -> List<T> {
+        return List::from(self.as_slice().concat());
+    }
 
-        return unsafe {
-            Some(&mut *self.ptr.as_ptr().add(index))
-        };
+    /// Flattens the [`List`] of slices into a single [`List`], cloning every item and placing `separator` between each.
+    /// The total length is computed up front, such that the result is built with a single allocation.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = list![&[1, 2][..], &[3][..]];
+    /// assert_eq!(list.join(&[0]), list![1, 2, 0, 3]);
+    /// ```
+    #[inline]
+    pub fn join(&self, separator: &[T]) -> List<T> {
+        return List::from(self.as_slice().join(separator));
     }
+}
 
-    /// Returns a reference to the item at the `front` of the list.
+
+impl List<String> {
+    /// Concatenates the [`List`] of [`String`]s into a single [`String`].
+    /// The total length is computed up front, such that the result is built with a single allocation.
     /// 
     /// ## Example
     /// ```rust
-    /// let list = list![2, 4, 6];
-    /// assert_eq!(list.front(), Some(&2));
+    /// let list = list![String::from("Hello"), String::from("World")];
+    /// assert_eq!(list.concat(), "HelloWorld");
     /// ```
     #[inline]
-    fn front(&self) -> Option<&T> {
-        return self.get(0);
+    pub fn concat(&self) -> String {
+        return self.as_slice().concat();
     }
 
-    /// Returns a reference to the item at the `back` of the list.
+    /// Concatenates the [`List`] of [`String`]s into a single [`String`], placing `separator` between each.
+    /// The total length is computed up front, such that the result is built with a single allocation.
     /// 
     /// ## Example
     /// ```rust
-    /// let list = list![2, 4, 6];
-    /// assert_eq!(list.back(), Some(&6));
+    /// let list = list![String::from("Hello"), String::from("World")];
+    /// assert_eq!(list.join(", "), "Hello, World");
     /// ```
     #[inline]
-    fn back(&self) -> Option<&T> {
-        return self.get(self.len - 1);
+    pub fn join(&self, separator: &str) -> String {
+        return self.as_slice().join(separator);
     }
+}
 
-    /// Returns a mutable reference to the item at the `front` of the list.
+
+impl List<&str> {
+    /// Concatenates the [`List`] of string slices into a single [`String`].
+    /// The total length is computed up front, such that the result is built with a single allocation.
     /// 
     /// ## Example
     /// ```rust
-    /// let mut list = list![2, 4, 6];
-    /// assert_eq!(list.front_mut(), Some(&mut 2));
+    /// let list = list!["Hello", "World"];
+    /// assert_eq!(list.concat(), "HelloWorld");
     /// ```
     #[inline]
-    fn front_mut(&mut self) -> Option<&mut T> {
-        return self.get_mut(0);
+    pub fn concat(&self) -> String {
+        return self.as_slice().concat();
     }
 
-    /// Returns a mutable reference to the item at the `back` of the list.
+    /// Concatenates the [`List`] of string slices into a single [`String`], placing `separator` between each.
+    /// The total length is computed up front, such that the result is built with a single allocation.
     /// 
     /// ## Example
     /// ```rust
-    /// let mut list = list![2, 4, 6];
-    /// assert_eq!(list.back_mut(), Some(&mut 6));
+    /// let list = list!["Hello", "World"];
+    /// assert_eq!(list.join(", "), "Hello, World");
     /// ```
     #[inline]
-    fn back_mut(&mut self) -> Option<&mut T> {
-        return self.get_mut(self.len - 1);
+    pub fn join(&self, separator: &str) -> String {
+        return self.as_slice().join(separator);
     }
 }
 
@@ -328,16 +3059,95 @@ impl<T> Drop for List<T> {
     fn drop(&mut self) {
         unsafe {
             drop_in_place(
-                from_raw_parts_mut(self.ptr.as_ptr(), self.len)
+                slice_from_raw_parts_mut(self.ptr.as_ptr(), self.len)
             );
 
-            let layout = alloc::Layout::from_size_align_unchecked(
-                size_of::<T>() * self.capacity,
-                align_of::<T>()
-            );
+            deallocate(self.ptr, self.capacity);
+        }
+    }
+}
+
+
+impl<T> Default for List<T> {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+
+impl<T: Clone> Clone for List<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut list = List::with_capacity(self.len);
+        list.growth = self.growth;
+
+        for item in self.iter() { list.push(item.clone()); }
+        return list;
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.truncate(source.len);
 
-            alloc::dealloc(self.ptr.as_ptr() as *mut u8, layout);
+        let mut iter = source.iter();
+
+        for (item, source) in self.iter_mut().zip(&mut iter) {
+            item.clone_from(source);
         }
+
+        self.reserve_exact(source.len - self.len);
+        for item in iter { self.push(item.clone()); }
+    }
+}
+
+
+impl<T> Deref for List<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        return self.as_slice();
+    }
+}
+
+
+impl<T> DerefMut for List<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        return self.as_mut_slice();
+    }
+}
+
+
+impl<T> AsRef<[T]> for List<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        return self.as_slice();
+    }
+}
+
+
+impl<T> AsMut<[T]> for List<T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        return self.as_mut_slice();
+    }
+}
+
+
+impl<T> Borrow<[T]> for List<T> {
+    #[inline]
+    fn borrow(&self) -> &[T] {
+        return self.as_slice();
+    }
+}
+
+
+impl<T> BorrowMut<[T]> for List<T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [T] {
+        return self.as_mut_slice();
     }
 }
 
@@ -348,7 +3158,7 @@ impl<T> Index<usize> for List<T> {
     #[inline]
     fn index(&self, index: usize) -> &Self::Output {
         return self.get(index)
-            .expect(format!("Index '{}' out of bounds.", index).as_str());
+            .unwrap_or_else(|| panic!("Index '{}' out of bounds.", index));
     }
 }
 
@@ -357,11 +3167,48 @@ impl<T> IndexMut<usize> for List<T> {
     #[inline]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         return self.get_mut(index)
-            .expect(format!("Index '{}' out of bounds.", index).as_str());
+            .unwrap_or_else(|| panic!("Index '{}' out of bounds.", index));
     }
 }
 
 
+/// Implements [`Index`] and [`IndexMut`] over a range type for [`List`], yielding slices of its initialized items.
+macro_rules! impl_range_index {
+    ( $( $range: ty ), * ) => {
+        $(
+            impl<T> Index<$range> for List<T> {
+                type Output = [T];
+
+                #[inline]
+                fn index(&self, range: $range) -> &Self::Output {
+                    let Range { start, end } = resolve_range(range, self.len);
+
+                    // SAFETY: `resolve_range()` guarantees that `start..end` lies within the initialized region.
+                    return unsafe { from_raw_parts(self.ptr.as_ptr().add(start), end - start) };
+                }
+            }
+
+
+            impl<T> IndexMut<$range> for List<T> {
+                #[inline]
+                fn index_mut(&mut self, range: $range) -> &mut Self::Output {
+                    let Range { start, end } = resolve_range(range, self.len);
+
+                    // SAFETY: `resolve_range()` guarantees that `start..end` lies within the initialized region.
+                    return unsafe { from_raw_parts_mut(self.ptr.as_ptr().add(start), end - start) };
+                }
+            }
+        )*
+    };
+}
+
+
+impl_range_index!(
+    Range<usize>, RangeFrom<usize>, RangeTo<usize>,
+    RangeFull, RangeInclusive<usize>, RangeToInclusive<usize>
+);
+
+
 impl<T: fmt::Debug> fmt::Debug for List<T> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -411,10 +3258,267 @@ impl<T: PartialEq> PartialEq for List<T> {
 impl<T: Eq> Eq for List<T> {  }
 
 
+impl<T: PartialEq> PartialEq<[T]> for List<T> {
+    #[inline]
+    fn eq(&self, other: &[T]) -> bool {
+        return self.len == other.len() && self.iter().eq(other.iter());
+    }
+}
+
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for List<T> {
+    #[inline]
+    fn eq(&self, other: &[T; N]) -> bool {
+        return *self == other[..];
+    }
+}
+
+
+impl<T: PartialEq> PartialEq<Vec<T>> for List<T> {
+    #[inline]
+    fn eq(&self, other: &Vec<T>) -> bool {
+        return *self == other[..];
+    }
+}
+
+
+impl<T: PartialEq> PartialEq<List<T>> for [T] {
+    #[inline]
+    fn eq(&self, other: &List<T>) -> bool {
+        return *other == *self;
+    }
+}
+
+
+impl<T: PartialEq, const N: usize> PartialEq<List<T>> for [T; N] {
+    #[inline]
+    fn eq(&self, other: &List<T>) -> bool {
+        return *other == self[..];
+    }
+}
+
+
+impl<T: PartialEq> PartialEq<List<T>> for Vec<T> {
+    #[inline]
+    fn eq(&self, other: &List<T>) -> bool {
+        return *other == self[..];
+    }
+}
+
+
+impl<T: PartialOrd> PartialOrd for List<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return self.iter().partial_cmp(other.iter());
+    }
+}
+
+
+impl<T: Ord> Ord for List<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        return self.iter().cmp(other.iter());
+    }
+}
+
+
+impl<T: Hash> Hash for List<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hashes exactly like `[T]`, which `Borrow<[T]>` requires.
+        self.as_slice().hash(state);
+    }
+}
+
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let list = ManuallyDrop::new(self);
+
+        return IntoIter {
+            ptr: list.ptr,
+            capacity: list.capacity,
+            start: 0,
+            end: list.len,
+        };
+    }
+}
+
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter();
+    }
+}
+
+
+impl<'a, T> IntoIterator for &'a mut List<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter_mut();
+    }
+}
+
+
+impl<T> Add for List<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        return self;
+    }
+}
+
+
+impl<T> AddAssign for List<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        if rhs.len == 0 { return; }
+
+        let rhs = ManuallyDrop::new(rhs);
+        let len = self.len + rhs.len;
+
+        self.reserve_exact(rhs.len);
+
+        /*
+            SAFETY:
+            - The buffer of `self` has room for `len` items, the items of `rhs` are moved into its uninitialized region.
+            - `rhs` is never dropped, so its moved items are not dropped twice; only its buffer is deallocated.
+        */
+        unsafe {
+            copy_nonoverlapping(rhs.ptr.as_ptr(), self.ptr.as_ptr().add(self.len), rhs.len);
+            deallocate(rhs.ptr, rhs.capacity);
+        }
+
+        self.len = len;
+    }
+}
+
+
+impl<T> From<Vec<T>> for List<T> {
+    /// Converts a [`Vec`] into a [`List`], reusing its allocation without copying any items.
+    #[inline]
+    fn from(vec: Vec<T>) -> Self {
+        let mut vec = ManuallyDrop::new(vec);
+
+        return Self {
+            // SAFETY: `Vec` always holds a non-null `ptr`, even when it has not allocated.
+            ptr: unsafe { NonNull::new_unchecked(vec.as_mut_ptr()) },
+            capacity: vec.capacity(),
+            len: vec.len(),
+            growth: GrowthPolicy::default(),
+        };
+    }
+}
+
+
+impl<T> From<List<T>> for Vec<T> {
+    /// Converts a [`List`] into a [`Vec`], reusing its allocation without copying any items.
+    #[inline]
+    fn from(list: List<T>) -> Self {
+        let list = ManuallyDrop::new(list);
+
+        /*
+            SAFETY:
+            - The buffer of the `List` was allocated by the global allocator with the same layout that a `Vec` uses.
+            - When `capacity` is `0` the `ptr` is dangling but aligned, which `Vec` accepts.
+        */
+        return unsafe { Vec::from_raw_parts(list.ptr.as_ptr(), list.len, list.capacity) };
+    }
+}
+
+
+impl<T> From<SinglyLinkedList<T>> for List<T> {
+    #[inline]
+    fn from(list: SinglyLinkedList<T>) -> Self {
+        return list.into_iter().collect();
+    }
+}
+
+
+impl<T> From<DoublyLinkedList<T>> for List<T> {
+    #[inline]
+    fn from(list: DoublyLinkedList<T>) -> Self {
+        return list.into_iter().collect();
+    }
+}
+
+
+impl<T: Clone> From<&[T]> for List<T> {
+    #[inline]
+    fn from(slice: &[T]) -> Self {
+        let mut list = List::new();
+        list.extend_from_slice(slice);
+
+        return list;
+    }
+}
+
+
+impl From<&str> for List<u8> {
+    /// Creates a [`List`] containing the UTF-8 bytes of the given `&str`.
+    #[inline]
+    fn from(string: &str) -> Self {
+        let mut list = List::new();
+        list.extend_from_copy_slice(string.as_bytes());
+
+        return list;
+    }
+}
+
+
+impl From<&str> for List<char> {
+    /// Creates a [`List`] containing the `char`s of the given `&str`.
+    #[inline]
+    fn from(string: &str) -> Self {
+        return string.chars().collect();
+    }
+}
+
+
+impl<T, const N: usize> From<[T; N]> for List<T> {
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        let array = ManuallyDrop::new(array);
+        let mut list = List::with_capacity(N);
+
+        // SAFETY: The `List` has room for `N` items, and the `array` is never dropped so its items are only moved.
+        unsafe { copy_nonoverlapping(array.as_ptr(), list.ptr.as_ptr(), N); }
+        list.len = N;
+
+        return list;
+    }
+}
+
+
+impl<T> Extend<T> for List<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+
+        for value in iter { self.push(value); }
+    }
+}
+
+
 impl<T> FromIterator<T> for List<T> {
     #[inline]
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let mut list = List::new();
+        let iter = iter.into_iter();
+        let mut list = List::with_capacity(iter.size_hint().0);
+
         for value in iter { list.push(value); }
         return list;
     }
@@ -1,3 +1,6 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
 use super::super::super::list;
 use super::List;
 
@@ -58,6 +61,7 @@ fn truncate() {
 
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn clear() {
     let mut list = list!["List", "is", "not", "clear"];
     assert_eq!(list.is_empty(), false);
@@ -115,4 +119,1443 @@ fn from_iter() {
         .collect::<List<&i32>>();
     
     assert_eq!(list, list![&1, &2, &3]);
-}
\ No newline at end of file
+}
+
+
+#[test]
+fn into_chunks() {
+    let list = list![1, 2, 3, 4, 5, 6, 7];
+    let mut chunks = list.into_chunks(3);
+
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks.next(), Some(list![1, 2, 3]));
+    assert_eq!(chunks.next(), Some(list![4, 5, 6]));
+    assert_eq!(chunks.next(), Some(list![7]));
+    assert_eq!(chunks.next(), None);
+
+    let list = list![String::from("a"), String::from("b"), String::from("c")];
+    let mut chunks = list.into_chunks(2);
+
+    assert_eq!(chunks.next(), Some(list![String::from("a"), String::from("b")]));
+    drop(chunks);
+
+    assert_eq!(List::<i32>::new().into_chunks(4).next(), None);
+}
+
+
+#[test]
+#[should_panic]
+fn into_chunks_zero() {
+    let _ = list![1, 2, 3].into_chunks(0);
+}
+
+#[test]
+fn add() {
+    let list = list![1, 2] + list![3, 4, 5];
+    assert_eq!(list, list![1, 2, 3, 4, 5]);
+
+    let mut list = List::new();
+    list += list![String::from("a")];
+    list += List::new();
+    list += list![String::from("b"), String::from("c")];
+
+    assert_eq!(list, list![String::from("a"), String::from("b"), String::from("c")]);
+}
+
+
+#[test]
+fn into_iter() {
+    let list = list![1, 2, 3, 4];
+    assert_eq!(list.into_iter().map(|x| x * 2).sum::<i32>(), 20);
+
+    let list = list![String::from("a"), String::from("b"), String::from("c")];
+    let mut iter = list.into_iter();
+
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(iter.next(), Some(String::from("a")));
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    drop(iter);
+
+    let mut count = 0;
+    for _ in List::<i32>::new() { count += 1; }
+    assert_eq!(count, 0);
+}
+
+
+#[test]
+fn iter() {
+    let mut list = list![1, 2, 3];
+
+    for item in list.iter_mut() {
+        *item *= 2;
+    }
+
+    for item in &mut list {
+        *item += 1;
+    }
+
+    assert_eq!(list.iter().sum::<i32>(), 15);
+    assert_eq!((&list).into_iter().collect::<Vec<_>>(), vec![&3, &5, &7]);
+    assert_eq!(List::<i32>::new().iter().next(), None);
+}
+
+
+#[test]
+fn iter_double_ended() {
+    let list = list![1, 2, 3, 4];
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+    assert_eq!(list.iter().len(), 4);
+
+    let mut iter = list.into_iter();
+
+    assert_eq!(iter.next_back(), Some(4));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next_back(), Some(3));
+    assert_eq!(iter.next_back(), Some(2));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+
+    let mut chunks = list![1, 2, 3, 4, 5].into_chunks(2);
+
+    assert_eq!(chunks.next_back(), Some(list![5]));
+    assert_eq!(chunks.next(), Some(list![1, 2]));
+    assert_eq!(chunks.next_back(), Some(list![3, 4]));
+    assert_eq!(chunks.next_back(), None);
+}
+
+
+#[test]
+fn clone() {
+    let list = list![String::from("a"), String::from("b")];
+    let mut cloned = list.clone();
+
+    cloned[0].push('!');
+
+    assert_eq!(list, list![String::from("a"), String::from("b")]);
+    assert_eq!(cloned, list![String::from("a!"), String::from("b")]);
+    assert_eq!(List::<i32>::new().clone(), List::new());
+}
+
+
+#[test]
+fn clone_from() {
+    let mut list = list![1, 2, 3, 4, 5];
+    let capacity = list.capacity();
+
+    list.clone_from(&list![6, 7]);
+    assert_eq!(list, list![6, 7]);
+    assert_eq!(list.capacity(), capacity);
+
+    list.clone_from(&list![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(list, list![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+}
+
+
+#[test]
+fn default() {
+    let list = List::<i32>::default();
+
+    assert!(list.is_empty());
+    assert_eq!(list.capacity(), 0);
+}
+
+
+fn hash_of<H: Hash>(value: &H) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    return hasher.finish();
+}
+
+
+#[test]
+fn hash() {
+    assert_eq!(hash_of(&list![1, 2, 3]), hash_of(&list![1, 2, 3]));
+    assert_ne!(hash_of(&list![1, 2, 3]), hash_of(&list![3, 2, 1]));
+    assert_ne!(hash_of(&list![vec![1], vec![2, 3]]), hash_of(&list![vec![1, 2], vec![3]]));
+}
+
+
+#[test]
+fn ord() {
+    assert!(list![1, 2, 3] < list![1, 2, 4]);
+    assert!(list![1, 2] < list![1, 2, 3]);
+    assert!(list![2] > list![1, 9, 9]);
+    assert_eq!(list![1, 2].cmp(&list![1, 2]), std::cmp::Ordering::Equal);
+    assert_eq!(list![1.0, f64::NAN].partial_cmp(&list![1.0, 2.0]), None);
+}
+
+
+#[test]
+fn from_iter_reserve() {
+    let list = (0 .. 10).collect::<List<_>>();
+
+    assert_eq!(list.len(), 10);
+    assert_eq!(list.capacity(), 10);
+    assert_eq!(list[9], 9);
+}
+
+
+#[test]
+fn extend() {
+    let mut list = list![1, 2];
+
+    list.extend(vec![3, 4]);
+    list.extend(5 ..= 6);
+    list.extend(std::iter::empty());
+
+    assert_eq!(list, list![1, 2, 3, 4, 5, 6]);
+    assert_eq!(list.len(), 6);
+}
+
+
+#[test]
+fn from_vec() {
+    let mut vec = Vec::with_capacity(8);
+    vec.extend([String::from("a"), String::from("b")]);
+
+    let ptr = vec.as_ptr();
+    let list = List::from(vec);
+
+    assert_eq!(list, list![String::from("a"), String::from("b")]);
+    assert_eq!(list.capacity(), 8);
+
+    let vec = Vec::from(list);
+
+    assert_eq!(vec, vec![String::from("a"), String::from("b")]);
+    assert_eq!(vec.as_ptr(), ptr);
+    assert_eq!(Vec::from(List::<i32>::new()), Vec::<i32>::new());
+    assert!(List::from(Vec::<i32>::new()).is_empty());
+}
+
+
+#[test]
+fn from_array() {
+    let list = List::from([String::from("a"), String::from("b"), String::from("c")]);
+
+    assert_eq!(list, list![String::from("a"), String::from("b"), String::from("c")]);
+    assert_eq!(list.len(), 3);
+    assert!(List::<i32>::from([]).is_empty());
+}
+
+
+#[test]
+fn from_linked() {
+    use crate::{sl_list, dl_list};
+
+    assert_eq!(List::from(sl_list![1, 2, 3]), list![1, 2, 3]);
+    assert_eq!(List::from(dl_list![String::from("a")]), list![String::from("a")]);
+}
+
+
+#[test]
+fn eq_foreign() {
+    let list = list![1, 2, 3];
+
+    assert_eq!(list, [1, 2, 3]);
+    assert_eq!(list, vec![1, 2, 3]);
+    assert_eq!(list, [1, 2, 3][..]);
+    assert_eq!([1, 2, 3], list);
+    assert_eq!(vec![1, 2, 3], list);
+    assert_eq!([1, 2, 3][..], list);
+
+    assert_ne!(list, [1, 2]);
+    assert_ne!(list, vec![1, 2, 4]);
+    assert_ne!([3, 2, 1], list);
+}
+
+
+#[test]
+fn deref() {
+    let mut list = list![5, 3, 1, 4, 2];
+
+    list.sort();
+    assert_eq!(list, [1, 2, 3, 4, 5]);
+    assert_eq!(list.binary_search(&4), Ok(3));
+    assert_eq!(list.windows(2).count(), 4);
+    assert_eq!(list.first(), Some(&1));
+
+    let slice: &[i32] = &List::new();
+    assert!(slice.is_empty());
+}
+
+
+#[test]
+fn as_slice() {
+    use std::io::Write;
+
+    let mut list = list![1u8, 2, 3];
+    list.as_mut_slice()[0] = 4;
+
+    assert_eq!(list.as_slice(), &[4, 2, 3]);
+    assert_eq!(List::<u8>::new().as_slice(), &[]);
+
+    let mut buffer = Vec::new();
+    buffer.write_all(list.as_slice()).unwrap();
+
+    assert_eq!(buffer, vec![4, 2, 3]);
+}
+
+
+#[test]
+fn into_slices() {
+    let mut list = List::with_capacity(8);
+    list.extend([String::from("a"), String::from("b")]);
+
+    let boxed = list.into_boxed_slice();
+    assert_eq!(&*boxed, &[String::from("a"), String::from("b")]);
+
+    let rc = list![1, 2, 3].into_rc_slice();
+    let arc = list![1, 2, 3].into_arc_slice();
+
+    assert_eq!(&*rc, &[1, 2, 3]);
+    assert_eq!(&*arc, &[1, 2, 3]);
+    assert!(List::<i32>::new().into_boxed_slice().is_empty());
+}
+
+
+#[test]
+fn as_ref_borrow() {
+    use std::borrow::{Borrow, BorrowMut};
+    use std::collections::HashSet;
+
+    fn sum<S: AsRef<[i32]>>(slice: S) -> i32 {
+        return slice.as_ref().iter().sum();
+    }
+
+    let mut list = list![1, 2, 3];
+
+    list.as_mut()[0] = 4;
+    BorrowMut::<[i32]>::borrow_mut(&mut list)[1] = 5;
+
+    assert_eq!(sum(&list), 12);
+    assert_eq!(Borrow::<[i32]>::borrow(&list), &[4, 5, 3]);
+
+    let mut set = HashSet::new();
+    set.insert(list![1, 2, 3]);
+
+    assert!(set.contains(&[1, 2, 3][..]));
+}
+
+
+#[test]
+fn from_borrowed() {
+    let slice: &[String] = &[String::from("a"), String::from("b")];
+
+    assert_eq!(List::from(slice), list![String::from("a"), String::from("b")]);
+    assert_eq!(List::<u8>::from("hé"), list![b'h', 0xC3, 0xA9]);
+    assert_eq!(List::<char>::from("hé"), list!['h', 'é']);
+    assert!(List::<u8>::from("").is_empty());
+}
+
+
+#[test]
+fn reserve() {
+    let mut list = list![1, 2, 3, 4];
+
+    list.reserve(1);
+    assert!(list.capacity() >= 5);
+
+    let capacity = list.capacity();
+    list.reserve(1);
+    assert_eq!(list.capacity(), capacity);
+
+    list.reserve_exact(100);
+    assert_eq!(list.capacity(), 104);
+
+    for i in 0 .. 100 { list.push(i); }
+    assert_eq!(list.capacity(), 104);
+
+    let mut list = List::<i32>::new();
+    list.reserve_exact(3);
+    assert_eq!(list.capacity(), 3);
+    assert_eq!(list, []);
+}
+
+
+#[test]
+fn shrink() {
+    let mut list = List::with_capacity(16);
+    list.extend([1, 2, 3]);
+
+    list.shrink_to(8);
+    assert_eq!(list.capacity(), 8);
+
+    list.shrink_to(10);
+    assert_eq!(list.capacity(), 8);
+
+    list.shrink_to_fit();
+    assert_eq!(list.capacity(), 3);
+    assert_eq!(list, [1, 2, 3]);
+
+    list.clear();
+    list.shrink_to_fit();
+    assert_eq!(list.capacity(), 0);
+
+    list.push(4);
+    assert_eq!(list, [4]);
+}
+
+
+#[test]
+fn try_reserve() {
+    use super::TryReserveError;
+
+    let mut list = List::<u64>::try_with_capacity(2).unwrap();
+
+    list.try_push(1).unwrap();
+    list.try_push(2).unwrap();
+    list.try_push(3).unwrap();
+    assert_eq!(list, [1, 2, 3]);
+
+    assert_eq!(list.try_reserve(usize::MAX), Err(TryReserveError::CapacityOverflow));
+    assert_eq!(list.try_reserve_exact(usize::MAX / 4), Err(TryReserveError::CapacityOverflow));
+    assert!(List::<u64>::try_with_capacity(usize::MAX / 8).is_err());
+    assert_eq!(list, [1, 2, 3]);
+
+    list.try_reserve_exact(7).unwrap();
+    assert_eq!(list.capacity(), 10);
+    assert_eq!(TryReserveError::CapacityOverflow.to_string(), "Capacity overflow.");
+}
+
+
+#[test]
+fn push_within_capacity() {
+    let mut list = List::new();
+    assert_eq!(list.push_within_capacity(1), Err(1));
+
+    list.reserve_exact(2);
+    assert_eq!(list.push_within_capacity(1), Ok(()));
+    assert_eq!(list.push_within_capacity(2), Ok(()));
+    assert_eq!(list.push_within_capacity(3), Err(3));
+
+    assert_eq!(list, [1, 2]);
+    assert_eq!(list.capacity(), 2);
+}
+
+
+#[test]
+fn zero_sized() {
+    use std::cell::Cell;
+
+    thread_local! {
+        static DROPS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    struct Zst;
+
+    impl Drop for Zst {
+        fn drop(&mut self) {
+            DROPS.with(|drops| drops.set(drops.get() + 1));
+        }
+    }
+
+    let mut list = List::new();
+    assert_eq!(list.capacity(), usize::MAX);
+
+    for _ in 0 .. 10 { list.push(Zst); }
+    assert_eq!(list.len(), 10);
+    assert_eq!(list.iter().count(), 10);
+
+    list.truncate(8);
+    assert_eq!(DROPS.with(Cell::get), 2);
+
+    let mut iter = list.into_iter();
+    assert!(iter.next_back().is_some());
+    assert_eq!(iter.len(), 7);
+    assert_eq!(DROPS.with(Cell::get), 3);
+
+    drop(iter);
+    assert_eq!(DROPS.with(Cell::get), 10);
+
+    let mut list = List::with_capacity(4);
+    list.extend([(), (), ()]);
+    list.shrink_to_fit();
+
+    assert_eq!(list.capacity(), usize::MAX);
+    assert_eq!(Vec::from(list.clone()), vec![(), (), ()]);
+    assert_eq!(list.into_chunks(2).count(), 2);
+}
+
+
+#[test]
+fn growth_policy() {
+    use super::{GrowthPolicy, INITIAL_CAPACITY};
+
+    let mut list = List::new();
+    list.push(1);
+
+    assert_eq!(list.growth(), GrowthPolicy::default());
+    assert_eq!(list.capacity(), INITIAL_CAPACITY);
+
+    let mut list = List::with_growth(GrowthPolicy::Multiply(3, 2));
+    let mut capacities = Vec::new();
+
+    for i in 0 .. 14 {
+        list.push(i);
+        if capacities.last() != Some(&list.capacity()) { capacities.push(list.capacity()); }
+    }
+
+    assert_eq!(capacities, vec![4, 6, 9, 13, 19]);
+
+    let mut list = List::with_growth(GrowthPolicy::Increment(3));
+    list.extend(0 .. 7);
+    assert_eq!(list.capacity(), 7);
+
+    list.push(7);
+    assert_eq!(list.capacity(), 10);
+    assert_eq!(list.clone().growth(), GrowthPolicy::Increment(3));
+
+    list.set_growth(GrowthPolicy::Multiply(1, 1));
+    list.extend([8, 9, 10]);
+    assert_eq!(list.capacity(), 11);
+}
+
+
+#[test]
+#[should_panic(expected = "Growth denominator must be non-zero.")]
+fn growth_policy_zero_denominator() {
+    use super::GrowthPolicy;
+
+    let _ = List::<i32>::with_growth(GrowthPolicy::Multiply(2, 0));
+}
+
+
+#[test]
+#[should_panic(expected = "Growth denominator must be non-zero.")]
+fn growth_policy_set_zero_denominator() {
+    use super::GrowthPolicy;
+
+    let mut list = list![1, 2, 3];
+    list.set_growth(GrowthPolicy::Multiply(3, 0));
+}
+
+
+#[test]
+#[should_panic(expected = "Growth denominator must be non-zero.")]
+fn growth_policy_grow_zero_denominator() {
+    use super::GrowthPolicy;
+
+    let _ = GrowthPolicy::Multiply(1, 0).grow(8);
+}
+
+
+#[test]
+fn push_grow() {
+    let mut list = List::with_capacity(2);
+
+    list.push(String::from("a"));
+    list.push(String::from("b"));
+    assert_eq!(list.capacity(), 2);
+
+    list.push(String::from("c"));
+    assert_eq!(list.capacity(), 4);
+    assert_eq!(list, [String::from("a"), String::from("b"), String::from("c")]);
+}
+
+
+#[test]
+fn raw_parts() {
+    let mut list = List::with_capacity(8);
+    list.extend([String::from("a"), String::from("b")]);
+
+    let (ptr, len, capacity) = list.into_raw_parts();
+    assert_eq!((len, capacity), (2, 8));
+
+    let mut list = unsafe { List::from_raw_parts(ptr, len, capacity) };
+    list.push(String::from("c"));
+
+    assert_eq!(list, [String::from("a"), String::from("b"), String::from("c")]);
+
+    let (ptr, len, capacity) = List::<i32>::new().into_raw_parts();
+    assert!(unsafe { List::from_raw_parts(ptr, len, capacity) }.is_empty());
+}
+
+
+#[test]
+fn spare_capacity_mut() {
+    let mut list = List::with_capacity(4);
+    list.push(String::from("a"));
+
+    let spare = list.spare_capacity_mut();
+    assert_eq!(spare.len(), 3);
+
+    spare[0].write(String::from("b"));
+    spare[1].write(String::from("c"));
+
+    unsafe { list.set_len(3); }
+    assert_eq!(list, [String::from("a"), String::from("b"), String::from("c")]);
+    assert_eq!(list.spare_capacity_mut().len(), 1);
+}
+
+
+#[test]
+fn leak() {
+    let slice: &'static mut [i32] = list![1, 2, 3].leak();
+    slice[2] = 4;
+
+    assert_eq!(slice, &[1, 2, 4]);
+    assert!(List::<i32>::new().leak().is_empty());
+}
+
+
+#[test]
+fn pop() {
+    let mut list = list![String::from("a"), String::from("b"), String::from("c")];
+
+    assert_eq!(list.pop(), Some(String::from("c")));
+    assert_eq!(list.pop_front(), Some(String::from("a")));
+    assert_eq!(list, [String::from("b")]);
+
+    assert_eq!(list.pop(), Some(String::from("b")));
+    assert_eq!(list.pop(), None);
+    assert_eq!(list.pop_front(), None);
+    assert_eq!(list.len(), 0);
+}
+
+
+#[test]
+fn insert() {
+    let mut list = List::with_capacity(2);
+
+    list.insert(0, String::from("b"));
+    list.insert(0, String::from("a"));
+    list.insert(2, String::from("d"));
+    list.insert(2, String::from("c"));
+
+    assert_eq!(list, [String::from("a"), String::from("b"), String::from("c"), String::from("d")]);
+}
+
+
+#[test]
+#[should_panic]
+fn insert_out_of_bounds() {
+    let mut list = list![1, 2, 3];
+    list.insert(4, 4);
+}
+
+
+#[test]
+fn remove() {
+    let mut list = list![String::from("a"), String::from("b"), String::from("c"), String::from("d")];
+
+    assert_eq!(list.remove(1), "b");
+    assert_eq!(list.remove(2), "d");
+    assert_eq!(list.remove(0), "a");
+    assert_eq!(list, [String::from("c")]);
+}
+
+
+#[test]
+#[should_panic]
+fn remove_out_of_bounds() {
+    let mut list = list![1, 2, 3];
+    list.remove(3);
+}
+
+
+#[test]
+fn swap_remove() {
+    let mut list = list![String::from("a"), String::from("b"), String::from("c"), String::from("d")];
+
+    assert_eq!(list.swap_remove(0), "a");
+    assert_eq!(list, [String::from("d"), String::from("b"), String::from("c")]);
+
+    assert_eq!(list.swap_remove(2), "c");
+    assert_eq!(list, [String::from("d"), String::from("b")]);
+}
+
+
+#[test]
+#[should_panic]
+fn swap_remove_out_of_bounds() {
+    let mut list = List::<i32>::new();
+    list.swap_remove(0);
+}
+
+
+#[test]
+fn drain() {
+    let mut list: List<String> = (0..6).map(|x| x.to_string()).collect();
+    let mut drain = list.drain(1..=4);
+
+    assert_eq!(drain.len(), 4);
+    assert_eq!(drain.next(), Some(String::from("1")));
+    assert_eq!(drain.next_back(), Some(String::from("4")));
+    drop(drain);
+
+    assert_eq!(list, [String::from("0"), String::from("5")]);
+
+    let drained: List<String> = list.drain(..).collect();
+    assert_eq!(drained, [String::from("0"), String::from("5")]);
+    assert!(list.is_empty());
+
+    let mut list = list![1, 2, 3];
+    core::mem::forget(list.drain(1..));
+    assert_eq!(list, list![1]);
+}
+
+
+#[test]
+#[should_panic]
+fn drain_out_of_bounds() {
+    let mut list = list![1, 2, 3];
+    list.drain(2..4);
+}
+
+
+#[test]
+fn retain() {
+    let mut list: List<String> = (0..8).map(|x| x.to_string()).collect();
+    list.retain(|x| x.parse::<i32>().unwrap() % 3 != 0);
+
+    assert_eq!(list, ["1", "2", "4", "5", "7"].map(String::from));
+
+    let mut list = list![1, 2, 3, 4];
+
+    list.retain_mut(|x| {
+        *x += 1;
+        return *x % 2 == 0;
+    });
+
+    assert_eq!(list, list![2, 4]);
+}
+
+
+#[test]
+fn retain_panic() {
+    let mut list: List<String> = (0..6).map(|x| x.to_string()).collect();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        list.retain(|x| {
+            if x == "4" { panic!(); }
+            return x != "1";
+        });
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(list, ["0", "2", "3", "4", "5"].map(String::from));
+}
+
+
+#[test]
+fn dedup() {
+    let mut list = List::from(["a", "a", "b", "c", "c", "c", "a"].map(String::from));
+    list.dedup();
+
+    assert_eq!(list, ["a", "b", "c", "a"].map(String::from));
+
+    let mut list = list!["a", "A", "b", "B", "b"];
+    list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    assert_eq!(list, list!["a", "b"]);
+
+    let mut list = list![1, 3, 2, 4, 5, 7];
+    list.dedup_by_key(|x| *x % 2);
+    assert_eq!(list, list![1, 2, 5]);
+
+    let mut list = List::<i32>::new();
+    list.dedup();
+    assert!(list.is_empty());
+}
+
+
+#[test]
+fn sort() {
+    let mut list = list![5, 3, 4, 1, 2];
+    list.sort();
+    assert_eq!(list, list![1, 2, 3, 4, 5]);
+
+    let mut list = list![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+    list.sort_by_key(|x| x.0);
+    assert_eq!(list, list![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+
+    list.sort_by(|a, b| b.0.cmp(&a.0));
+    assert_eq!(list, list![(2, 'a'), (2, 'c'), (1, 'b'), (1, 'd')]);
+}
+
+
+#[test]
+fn sort_unstable() {
+    let mut list: List<u64> = (0..1000).map(|x| (x * 7919) % 1000).collect();
+    list.sort_unstable();
+    assert!(list.iter().copied().eq(0..1000));
+
+    list.sort_unstable_by(|a, b| b.cmp(a));
+    assert!(list.iter().copied().eq((0..1000).rev()));
+
+    list.sort_unstable_by_key(|x| *x % 10);
+    assert!(list.windows(2).all(|pair| pair[0] % 10 <= pair[1] % 10));
+}
+
+
+#[test]
+fn binary_search() {
+    let mut list = List::new();
+
+    for value in [5, 1, 4, 2, 3] {
+        let index = list.binary_search(&value).unwrap_or_else(|index| index);
+        list.insert(index, value);
+    }
+
+    assert_eq!(list, list![1, 2, 3, 4, 5]);
+    assert_eq!(list.binary_search(&4), Ok(3));
+    assert_eq!(list.binary_search(&6), Err(5));
+    assert_eq!(list.binary_search_by(|x| x.cmp(&0)), Err(0));
+    assert_eq!(list.binary_search_by_key(&4, |x| x * 2), Ok(1));
+    assert_eq!(list.partition_point(|&x| x <= 2), 2);
+}
+
+
+#[test]
+fn reverse() {
+    let mut list = list![String::from("a"), String::from("b"), String::from("c"), String::from("d")];
+    list.reverse();
+    assert_eq!(list, [String::from("d"), String::from("c"), String::from("b"), String::from("a")]);
+
+    let mut list = list![1, 2, 3];
+    list.reverse();
+    assert_eq!(list, list![3, 2, 1]);
+
+    let mut list = List::<i32>::new();
+    list.reverse();
+    assert!(list.is_empty());
+}
+
+
+#[test]
+fn rotate() {
+    let mut list: List<String> = (0..5).map(|x| x.to_string()).collect();
+
+    list.rotate_left(2);
+    assert_eq!(list, ["2", "3", "4", "0", "1"].map(String::from));
+
+    list.rotate_right(2);
+    assert_eq!(list, ["0", "1", "2", "3", "4"].map(String::from));
+
+    list.rotate_left(5);
+    list.rotate_right(0);
+    assert_eq!(list, ["0", "1", "2", "3", "4"].map(String::from));
+}
+
+
+#[test]
+#[should_panic]
+fn rotate_out_of_bounds() {
+    let mut list = list![1, 2, 3];
+    list.rotate_right(4);
+}
+
+
+#[test]
+fn split_off() {
+    let mut list: List<String> = (0..5).map(|x| x.to_string()).collect();
+    let tail = list.split_off(2);
+
+    assert_eq!(list, ["0", "1"].map(String::from));
+    assert_eq!(tail, ["2", "3", "4"].map(String::from));
+
+    let tail = list.split_off(2);
+    assert!(tail.is_empty());
+
+    let tail = list.split_off(0);
+    assert!(list.is_empty());
+    assert_eq!(tail, ["0", "1"].map(String::from));
+}
+
+
+#[test]
+#[should_panic]
+fn split_off_out_of_bounds() {
+    let mut list = list![1, 2, 3];
+    list.split_off(4);
+}
+
+
+#[test]
+fn split_at() {
+    let list: List<String> = (0..4).map(|x| x.to_string()).collect();
+    let (front, back) = list.split_at(3);
+
+    assert_eq!(front, ["0", "1", "2"].map(String::from));
+    assert_eq!(back, [String::from("3")]);
+
+    let (front, back) = back.split_at(0);
+    assert!(front.is_empty());
+    assert_eq!(back, [String::from("3")]);
+}
+
+
+#[test]
+#[should_panic]
+fn split_at_out_of_bounds() {
+    list![1, 2, 3].split_at(4);
+}
+
+
+#[test]
+fn fill() {
+    let mut list = list![String::from("a"), String::from("b"), String::from("c")];
+
+    list.fill(String::from("x"));
+    assert_eq!(list, ["x", "x", "x"].map(String::from));
+
+    let mut count = 0;
+
+    list.fill_with(|| {
+        count += 1;
+        return count.to_string();
+    });
+
+    assert_eq!(list, ["1", "2", "3"].map(String::from));
+}
+
+
+#[test]
+fn resize() {
+    let mut list = list![String::from("a")];
+
+    list.resize(3, String::from("b"));
+    assert_eq!(list, ["a", "b", "b"].map(String::from));
+
+    list.resize(1, String::from("c"));
+    assert_eq!(list, [String::from("a")]);
+
+    let mut count = 0;
+
+    list.resize_with(3, || {
+        count += 1;
+        return count.to_string();
+    });
+
+    assert_eq!(list, ["a", "1", "2"].map(String::from));
+
+    list.resize_with(0, String::new);
+    assert!(list.is_empty());
+}
+
+
+#[test]
+fn extend_from_slice() {
+    let mut list = list![String::from("a")];
+    list.extend_from_slice(&[String::from("b"), String::from("c")]);
+
+    assert_eq!(list, ["a", "b", "c"].map(String::from));
+
+    let mut bytes = List::from("Hello");
+    bytes.extend_from_copy_slice(b", World!");
+    bytes.extend_from_copy_slice(&[]);
+
+    assert_eq!(bytes, List::from("Hello, World!"));
+    assert_eq!(bytes.len(), 13);
+}
+
+
+#[test]
+fn extend_from_within() {
+    let mut list = list![String::from("a"), String::from("b"), String::from("c")];
+
+    list.extend_from_within(..2);
+    assert_eq!(list, ["a", "b", "c", "a", "b"].map(String::from));
+
+    list.extend_from_within(2..=3);
+    assert_eq!(list, ["a", "b", "c", "a", "b", "c", "a"].map(String::from));
+
+    list.extend_from_within(7..);
+    assert_eq!(list.len(), 7);
+}
+
+
+#[test]
+#[should_panic]
+fn extend_from_within_out_of_bounds() {
+    let mut list = list![1, 2, 3];
+    list.extend_from_within(..4);
+}
+
+
+#[test]
+fn contains() {
+    let list = list![String::from("a"), String::from("b")];
+
+    assert!(list.contains(&String::from("b")));
+    assert!(!list.contains(&String::from("c")));
+    assert!(!List::<i32>::new().contains(&0));
+}
+
+
+#[test]
+fn first_last() {
+    let mut list = list![2, 4, 6];
+
+    assert_eq!(list.first(), Some(&2));
+    assert_eq!(list.last(), Some(&6));
+
+    *list.first_mut().unwrap() += 1;
+    *list.last_mut().unwrap() += 1;
+
+    assert_eq!(list, list![3, 4, 7]);
+
+    let mut list = List::<i32>::new();
+
+    assert_eq!(list.back(), None);
+    assert_eq!(list.back_mut(), None);
+    assert_eq!(list.last(), None);
+    assert_eq!(list.first(), None);
+}
+
+
+#[test]
+fn range_index() {
+    let mut list = list![1, 2, 3, 4, 5];
+
+    assert_eq!(&list[1..4], &[2, 3, 4]);
+    assert_eq!(&list[..2], &[1, 2]);
+    assert_eq!(&list[3..], &[4, 5]);
+    assert_eq!(&list[..=1], &[1, 2]);
+    assert_eq!(&list[1..=1], &[2]);
+    assert_eq!(&list[..], &[1, 2, 3, 4, 5]);
+    assert!(list[5..].is_empty());
+
+    list[1..3].fill(0);
+    list[3..].reverse();
+
+    assert_eq!(list, list![1, 0, 0, 5, 4]);
+}
+
+
+#[test]
+#[should_panic]
+fn range_index_out_of_bounds() {
+    let list = list![1, 2, 3];
+    let _ = &list[2..4];
+}
+
+
+#[test]
+fn as_ptr() {
+    let mut list = list![1, 2, 3];
+
+    assert_eq!(list.as_ptr(), list.as_slice().as_ptr());
+    assert_eq!(unsafe { *list.as_ptr().add(1) }, 2);
+
+    let ptr = list.as_mut_ptr();
+    unsafe { *ptr = 4; }
+
+    assert_eq!(list, list![4, 2, 3]);
+    assert!(!List::<i32>::new().as_ptr().is_null());
+}
+
+
+#[test]
+fn get_unchecked() {
+    let mut list = list![1, 2, 3];
+    let mut sum = 0;
+
+    for index in 0..list.len() {
+        sum += unsafe { *list.get_unchecked(index) };
+        unsafe { *list.get_unchecked_mut(index) *= 2; }
+    }
+
+    assert_eq!(sum, 6);
+    assert_eq!(list, list![2, 4, 6]);
+}
+
+
+#[test]
+fn get_many_mut() {
+    use super::GetManyMutError;
+
+    let mut list = list![String::from("a"), String::from("b"), String::from("c")];
+
+    let [a, c] = list.get_many_mut([0, 2]).unwrap();
+    core::mem::swap(a, c);
+    assert_eq!(list, ["c", "b", "a"].map(String::from));
+
+    assert_eq!(list.get_many_mut([0, 3]).unwrap_err(), GetManyMutError::IndexOutOfBounds(3));
+    assert_eq!(list.get_many_mut([1, 2, 1]).unwrap_err(), GetManyMutError::OverlappingIndices(1));
+    assert!(list.get_many_mut([]).is_ok());
+}
+
+
+#[test]
+fn windows() {
+    let list = list![1, 2, 3, 4, 5];
+    let averages: List<i32> = list.windows(3).map(|window| window.iter().sum::<i32>() / 3).collect();
+
+    assert_eq!(averages, list![2, 3, 4]);
+    assert_eq!(list.windows(5).count(), 1);
+    assert_eq!(list.windows(6).count(), 0);
+}
+
+
+#[test]
+#[should_panic]
+fn windows_zero_size() {
+    let _ = list![1, 2, 3].windows(0);
+}
+
+
+#[test]
+fn chunks() {
+    let mut list = list![1, 2, 3, 4, 5, 6, 7];
+
+    let sums: List<i32> = list.chunks(3).map(|chunk| chunk.iter().sum()).collect();
+    assert_eq!(sums, list![6, 15, 7]);
+
+    for chunk in list.chunks_mut(2) { chunk.reverse(); }
+    assert_eq!(list, list![2, 1, 4, 3, 6, 5, 7]);
+
+    let mut chunks = list.chunks_exact(3);
+
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks.next(), Some(&[2, 1, 4][..]));
+    assert_eq!(chunks.next(), Some(&[3, 6, 5][..]));
+    assert_eq!(chunks.next(), None);
+    assert_eq!(chunks.remainder(), &[7]);
+}
+
+
+#[test]
+fn is_sorted() {
+    assert!(list![1, 2, 2, 5].is_sorted());
+    assert!(!list![1, 3, 2].is_sorted());
+    assert!(List::<i32>::new().is_sorted());
+    assert!(!list![1.0, f64::NAN].is_sorted());
+
+    let list = list!["c", "bb", "aaa"];
+
+    assert!(list.is_sorted_by_key(|x| x.len()));
+    assert!(list.is_sorted_by(|a, b| a >= b));
+    assert!(!list.is_sorted_by(|a, b| a <= b));
+}
+
+
+#[test]
+fn prefix_suffix() {
+    let list = List::from("GET /index HTTP/1.1");
+
+    assert!(list.starts_with(b"GET"));
+    assert!(list.ends_with(b"HTTP/1.1"));
+    assert!(!list.starts_with(b"POST"));
+    assert!(list.starts_with(&[]));
+
+    let request = list.strip_prefix(b"GET ").unwrap();
+    assert_eq!(request, b"/index HTTP/1.1");
+
+    assert_eq!(list.strip_suffix(b" HTTP/1.1"), Some(&b"GET /index"[..]));
+    assert_eq!(list.strip_suffix(b"HTTP/2"), None);
+}
+
+
+#[test]
+fn find_sublist() {
+    let list = List::from("abaabaabbab");
+
+    assert_eq!(list.find_sublist(b"aabb"), Some(5));
+    assert_eq!(list.find_sublist(b"abaab"), Some(0));
+    assert_eq!(list.find_sublist(b"bab"), Some(8));
+    assert_eq!(list.find_sublist(b"bb"), Some(7));
+    assert_eq!(list.find_sublist(b"abc"), None);
+    assert_eq!(list.find_sublist(b""), Some(0));
+    assert_eq!(list![1].find_sublist(&[1, 1]), None);
+
+    let haystack: List<u8> = (0..200).map(|x| (x % 3) as u8).collect();
+    let needle = [2, 0, 1, 2, 0];
+
+    assert_eq!(haystack.find_sublist(&needle), haystack.windows(needle.len()).position(|w| w == needle));
+}
+
+
+#[test]
+fn position_find() {
+    let list = list![1, 2, 3, 4];
+
+    assert_eq!(list.position(|x| *x == 3), Some(2));
+    assert_eq!(list.find(|x| x % 2 == 0), Some(&2));
+    assert_eq!(list.rfind(|x| x % 2 == 0), Some(&4));
+    assert_eq!(list.position(|x| *x == 5), None);
+    assert_eq!(list.rfind(|x| *x == 5), None);
+}
+
+
+#[test]
+fn extract_if() {
+    let mut list: List<String> = (0..8).map(|x| x.to_string()).collect();
+    let extracted: List<String> = list.extract_if(|x| x.parse::<i32>().unwrap() % 3 == 0).collect();
+
+    assert_eq!(extracted, ["0", "3", "6"].map(String::from));
+    assert_eq!(list, ["1", "2", "4", "5", "7"].map(String::from));
+
+    let mut iter = list.extract_if(|x| x.len() == 1);
+    assert_eq!(iter.next(), Some(String::from("1")));
+    drop(iter);
+
+    assert_eq!(list, ["2", "4", "5", "7"].map(String::from));
+
+    let mut list = list![1, 2, 3, 4];
+    list.extract_if(|x| { *x *= 10; return *x > 20; }).for_each(drop);
+
+    assert_eq!(list, list![10, 20]);
+}
+
+
+#[test]
+fn truncate_front() {
+    let mut list: List<String> = (0..6).map(|x| x.to_string()).collect();
+
+    list.truncate_front(2);
+    assert_eq!(list, ["2", "3", "4", "5"].map(String::from));
+
+    list.keep_last(3);
+    assert_eq!(list, ["3", "4", "5"].map(String::from));
+
+    list.keep_last(4);
+    list.truncate_front(0);
+    assert_eq!(list.len(), 3);
+
+    list.truncate_front(4);
+    assert!(list.is_empty());
+}
+
+
+#[test]
+fn list_macro_repeat() {
+    let list = list![String::from("a"); 3];
+
+    assert_eq!(list, ["a", "a", "a"].map(String::from));
+    assert_eq!(list.capacity(), 3);
+    assert!(list![0; 0].is_empty());
+
+    let list = list![
+        1,
+        2,
+    ];
+
+    assert_eq!(list, [1, 2]);
+}
+
+
+#[test]
+fn repeat() {
+    let list = list![String::from("a"), String::from("b")];
+    let repeated = list.repeat(3);
+
+    assert_eq!(repeated, ["a", "b", "a", "b", "a", "b"].map(String::from));
+    assert_eq!(repeated.capacity(), 6);
+    assert!(list.repeat(0).is_empty());
+    assert!(List::<i32>::new().repeat(5).is_empty());
+}
+
+
+#[test]
+#[should_panic]
+fn repeat_overflow() {
+    list![1, 2].repeat(usize::MAX);
+}
+
+
+#[test]
+fn concat_join() {
+    let nested = list![list![1, 2], List::new(), list![3]];
+
+    assert_eq!(nested.concat(), list![1, 2, 3]);
+    assert_eq!(nested.join(&[0, 0]), list![1, 2, 0, 0, 0, 0, 3]);
+    assert_eq!(nested.concat().capacity(), 3);
+
+    let slices = list![&[1][..], &[2, 3][..]];
+
+    assert_eq!(slices.concat(), list![1, 2, 3]);
+    assert_eq!(slices.join(&[]), list![1, 2, 3]);
+
+    let strings = list![String::from("a"), String::from("b"), String::from("c")];
+
+    assert_eq!(strings.concat(), "abc");
+    assert_eq!(strings.join("/"), "a/b/c");
+    assert_eq!(list!["a", "b"].join(", "), "a, b");
+    assert_eq!(List::<&str>::new().join(", "), "");
+}
+
+
+#[test]
+fn flatten() {
+    let nested = list![
+        list![String::from("a"), String::from("b")],
+        List::new(),
+        list![String::from("c")],
+    ];
+
+    let list = nested.flatten();
+
+    assert_eq!(list, ["a", "b", "c"].map(String::from));
+    assert_eq!(list.capacity(), 3);
+    assert!(List::<List<i32>>::new().flatten().is_empty());
+}
+
+
+#[test]
+fn combinators() {
+    let list: List<i32> = (1..=6).collect();
+
+    let strings = list.clone().map(|x| x.to_string());
+    assert_eq!(strings, ["1", "2", "3", "4", "5", "6"].map(String::from));
+    assert_eq!(strings.capacity(), 6);
+
+    let odds = strings.filter_map(|x| x.parse::<i32>().ok().filter(|x| x % 2 == 1));
+    assert_eq!(odds, list![1, 3, 5]);
+
+    let evens = list.clone().filter(|x| x % 2 == 0);
+    assert_eq!(evens, list![2, 4, 6]);
+
+    assert_eq!(list.fold(String::new(), |string, x| string + &x.to_string()), "123456");
+}
+
+
+#[test]
+fn try_map() {
+    let list = list![String::from("1"), String::from("2")];
+    assert_eq!(list.try_map(|x| x.parse::<i32>()), Ok(list![1, 2]));
+
+    let list = list![String::from("1"), String::from("a"), String::from("3")];
+    let mut mapped = 0;
+
+    let result = list.try_map(|x| {
+        mapped += 1;
+        return x.parse::<i32>().map(|x| x.to_string());
+    });
+
+    assert!(result.is_err());
+    assert_eq!(mapped, 2);
+}
+
+
+#[test]
+fn zip() {
+    let names = list![String::from("a"), String::from("b"), String::from("c")];
+    let zipped = names.clone().zip(list![1, 2]);
+
+    assert_eq!(zipped, list![(String::from("a"), 1), (String::from("b"), 2)]);
+    assert_eq!(zipped.capacity(), 2);
+
+    let joined = names.zip_with(list![1, 2, 3, 4], |name, x| format!("{}{}", name, x));
+    assert_eq!(joined, ["a1", "b2", "c3"].map(String::from));
+}
+
+
+#[test]
+fn unzip() {
+    let list = list![(String::from("a"), 1), (String::from("b"), 2)];
+    let (names, numbers) = list.unzip();
+
+    assert_eq!(names, ["a", "b"].map(String::from));
+    assert_eq!(numbers, list![1, 2]);
+    assert_eq!((names.capacity(), numbers.capacity()), (2, 2));
+}
+
+
+#[test]
+fn partition() {
+    let list = list![String::from("a"), String::from("bb"), String::from("c"), String::from("dd")];
+    let (short, long) = list.partition(|x| x.len() == 1);
+
+    assert_eq!(short, ["a", "c"].map(String::from));
+    assert_eq!(long, ["bb", "dd"].map(String::from));
+    assert_eq!((short.len(), long.len()), (2, 2));
+
+    let (all, none) = list![1, 2, 3].partition(|_| true);
+
+    assert_eq!(all, [1, 2, 3]);
+    assert!(none.is_empty());
+}
+
+
+#[test]
+fn interleave() {
+    let left = list![String::from("l1"), String::from("l2")];
+    let right = list![String::from("r1"), String::from("r2"), String::from("r3")];
+
+    let list = left.interleave(right);
+
+    assert_eq!(list, ["l1", "r1", "l2", "r2", "r3"].map(String::from));
+    assert_eq!(list.capacity(), 5);
+
+    assert_eq!(list![1, 3, 5].interleave(list![2]), list![1, 2, 3, 5]);
+    assert_eq!(List::new().interleave(list![1]), list![1]);
+}
+
+
+#[test]
+fn chunk_by() {
+    let list = list![1, 2, 3, 2, 3, 1, 1];
+    let runs: List<&[i32]> = list.chunk_by(|a, b| a < b).collect();
+
+    assert_eq!(runs, list![&[1, 2, 3][..], &[2, 3][..], &[1][..], &[1][..]]);
+    assert_eq!(list.chunk_by(|_, _| true).count(), 1);
+    assert_eq!(List::<i32>::new().chunk_by(|_, _| true).count(), 0);
+}
+
+
+#[test]
+fn merge_sorted() {
+    let a = list![(1, 'a'), (3, 'a'), (5, 'a')];
+    let b = list![(0, 'b'), (3, 'b'), (6, 'b'), (7, 'b')];
+
+    let list = a.merge_sorted(b);
+
+    assert_eq!(list.iter().map(|x| x.0).collect::<List<_>>(), list![0, 1, 3, 3, 5, 6, 7]);
+    assert_eq!(list.capacity(), 7);
+
+    let list = list![1, 2].merge_sorted(List::new());
+    assert_eq!(list, list![1, 2]);
+}
+
+
+#[test]
+fn kmerge() {
+    let lists = list![
+        list![String::from("b"), String::from("e")],
+        List::new(),
+        list![String::from("a"), String::from("d"), String::from("f")],
+        list![String::from("c")],
+    ];
+
+    let list = lists.kmerge();
+
+    assert_eq!(list, ["a", "b", "c", "d", "e", "f"].map(String::from));
+    assert_eq!(list.capacity(), 6);
+    assert!(List::<List<i32>>::new().kmerge().is_empty());
+}
+
+
+#[test]
+fn split_into() {
+    let list: List<String> = (0..7).map(|x| x.to_string()).collect();
+    let parts = list.split_into(3);
+
+    assert_eq!(parts.len(), 3);
+    assert_eq!(parts[0], ["0", "1", "2"].map(String::from));
+    assert_eq!(parts[1], ["3", "4"].map(String::from));
+    assert_eq!(parts[2], ["5", "6"].map(String::from));
+
+    let parts = list![1, 2].split_into(4);
+    assert_eq!(parts, list![list![1], list![2], List::new(), List::new()]);
+}
+
+
+#[test]
+#[should_panic]
+fn split_into_zero() {
+    list![1, 2, 3].split_into(0);
+}
+
+
+#[test]
+fn dedup_with_counts() {
+    let list = List::from("aaabccdd");
+    let encoded = list.clone().dedup_with_counts();
+
+    assert_eq!(encoded, list![(b'a', 3), (b'b', 1), (b'c', 2), (b'd', 2)]);
+    assert_eq!(encoded.decode(), list);
+
+    assert!(List::<i32>::new().dedup_with_counts().is_empty());
+    assert_eq!(list![(String::from("x"), 0), (String::from("y"), 2)].decode(), ["y", "y"].map(String::from));
+}
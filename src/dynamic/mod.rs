@@ -12,4 +12,4 @@
 pub mod list;
 
 
-pub use list::List;
\ No newline at end of file
+pub use list::{List, GrowthPolicy};
\ No newline at end of file
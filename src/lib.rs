@@ -9,6 +9,9 @@
 //! ```
 
 
+#![allow(clippy::needless_return)]
+
+
 pub mod linked;
 pub mod dynamic;
 
@@ -18,7 +21,7 @@ pub use linked::doubly::DoublyLinkedList;
 pub use dynamic::list::List;
 
 
-/// Shorthand syntax for creating a [`SinglyLinkedList`].
+/// Shorthand syntax for creating a [`SinglyLinkedList`], either from a list of values or by cloning a value `n` times.
 /// Time complexity is `O(n)`.
 /// 
 /// ## Example
@@ -28,10 +31,18 @@ pub use dynamic::list::List;
 /// assert_eq!(list.len(), 5);
 /// assert_eq!(list.front(), Some(&1));
 /// assert_eq!(list.back(), Some(&5));
+/// 
+/// assert_eq!(sl_list![0; 3], sl_list![0, 0, 0,]);
 /// ```
 #[macro_export]
 macro_rules! sl_list {
-    ( $( $element: expr ), * ) => {
+    ( $element: expr; $count: expr ) => {
+        <$crate::linked::singly::SinglyLinkedList<_> as ::core::iter::FromIterator<_>>::from_iter(
+            ::core::iter::repeat($element).take($count)
+        )
+    };
+
+    ( $( $element: expr ), * $(,)? ) => {
         {
             let mut list = $crate::linked::singly::SinglyLinkedList::new();
             $( list.push_back($element); ) *
@@ -41,7 +52,7 @@ macro_rules! sl_list {
 }
 
 
-/// Shorthand syntax for creating a [`DoublyLinkedList`].
+/// Shorthand syntax for creating a [`DoublyLinkedList`], either from a list of values or by cloning a value `n` times.
 /// Time complexity is `O(1)`.
 /// 
 /// ## Example
@@ -51,10 +62,18 @@ macro_rules! sl_list {
 /// assert_eq!(list.len(), 5);
 /// assert_eq!(list.front(), Some(&1));
 /// assert_eq!(list.back(), Some(&5));
+/// 
+/// assert_eq!(dl_list![0; 3], dl_list![0, 0, 0,]);
 /// ```
 #[macro_export]
 macro_rules! dl_list {
-    ( $( $element: expr ), * ) => {
+    ( $element: expr; $count: expr ) => {
+        <$crate::linked::doubly::DoublyLinkedList<_> as ::core::iter::FromIterator<_>>::from_iter(
+            ::core::iter::repeat($element).take($count)
+        )
+    };
+
+    ( $( $element: expr ), * $(,)? ) => {
         {
             let mut list = $crate::linked::doubly::DoublyLinkedList::new();
             $( list.push_back($element); ) *
@@ -64,7 +83,8 @@ macro_rules! dl_list {
 }
 
 
-/// Shorthand syntax for creating a [`List`].
+/// Shorthand syntax for creating a [`List`], either from a list of values or by cloning a value `n` times.
+/// The repetition form allocates exactly `n` items up front.
 /// Time complexity is `O(1)`.
 /// 
 /// ## Example
@@ -74,10 +94,22 @@ macro_rules! dl_list {
 /// assert_eq!(list.len(), 5);
 /// assert_eq!(list[0], 1);
 /// assert_eq!(list[4], 5);
+/// 
+/// assert_eq!(list![0; 3], list![0, 0, 0,]);
 /// ```
 #[macro_export]
 macro_rules! list {
-    ( $( $element: expr ), * ) => {
+    ( $element: expr; $count: expr ) => {
+        {
+            let count = $count;
+            let mut list = $crate::dynamic::list::List::with_capacity(count);
+
+            list.resize(count, $element);
+            list
+        }
+    };
+
+    ( $( $element: expr ), * $(,)? ) => {
         {
             let mut list = $crate::dynamic::list::List::new();
             $( list.push($element); ) *
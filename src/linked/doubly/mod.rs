@@ -17,10 +17,16 @@ mod tests;
 pub mod node;
 
 use node::Node;
-use core::ptr::{read as ptr_read, NonNull};
-use core::iter::{Iterator, IntoIterator, DoubleEndedIterator, FusedIterator, ExactSizeIterator, FromIterator};
-use core::ops::{Index, IndexMut};
-use core::cmp::{Eq, PartialEq};
+use super::singly::SinglyLinkedList;
+use crate::dynamic::list::List;
+use core::ptr::NonNull;
+use core::mem::{forget, take};
+use std::boxed::Box;
+use core::iter::{Iterator, IntoIterator, DoubleEndedIterator, FusedIterator, ExactSizeIterator, FromIterator, Extend};
+use core::ops::{Index, IndexMut, Add, AddAssign};
+use core::marker::PhantomData;
+use core::cmp::{Eq, PartialEq, Ord, PartialOrd, Ordering};
+use core::hash::{Hash, Hasher};
 use core::option::Option;
 use core::fmt;
 
@@ -38,14 +44,74 @@ pub struct DoublyLinkedList<T> {
 }
 
 
-/// [`Iter`] for a [`DoublyLinkedList`], it is the list's struct for their `IntoIter` trait.
-pub struct Iter<T> {
+/// [`IntoIter`] for a [`DoublyLinkedList`], it is the list's struct for their `IntoIter` trait.
+pub struct IntoIter<T> {
     /// [`DoublyLinkedList`] used in iterating over.
     list: DoublyLinkedList<T>,
 }
 
 
-impl<T> Iterator for Iter<T> {
+/// Borrowing [`Iterator`] over the values of a [`DoublyLinkedList`], created by [`DoublyLinkedList::iter()`].
+pub struct Iter<'a, T> {
+    /// Next [`Node`] to be yielded from the `front`.
+    head: Option<NonNull<Node<T>>>,
+
+    /// Next [`Node`] to be yielded from the `back`.
+    tail: Option<NonNull<Node<T>>>,
+
+    /// Number of [`Node`]s that have yet to be yielded.
+    len: usize,
+
+    /// Binds the [`Iter`] to the lifetime of the borrowed [`DoublyLinkedList`].
+    marker: PhantomData<&'a Node<T>>,
+}
+
+
+/// Mutably borrowing [`Iterator`] over the values of a [`DoublyLinkedList`], created by [`DoublyLinkedList::iter_mut()`].
+pub struct IterMut<'a, T> {
+    /// Next [`Node`] to be yielded from the `front`.
+    head: Option<NonNull<Node<T>>>,
+
+    /// Next [`Node`] to be yielded from the `back`.
+    tail: Option<NonNull<Node<T>>>,
+
+    /// Number of [`Node`]s that have yet to be yielded.
+    len: usize,
+
+    /// Binds the [`IterMut`] to the lifetime of the mutably borrowed [`DoublyLinkedList`].
+    marker: PhantomData<&'a mut Node<T>>,
+}
+
+
+/// Cursor over a [`DoublyLinkedList`], created by [`DoublyLinkedList::cursor_front()`] or [`DoublyLinkedList::cursor_back()`].
+/// A cursor points at a [`Node`] or at the "ghost" position between the `back` and the `front` of the list.
+pub struct Cursor<'a, T> {
+    /// [`Node`] the cursor is pointing at, `None` when pointing at the ghost position.
+    current: Option<NonNull<Node<T>>>,
+
+    /// Index of `current`, equal to the list's `len` when pointing at the ghost position.
+    index: usize,
+
+    /// [`DoublyLinkedList`] the cursor is borrowing.
+    list: &'a DoublyLinkedList<T>,
+}
+
+
+/// Cursor over a [`DoublyLinkedList`] that can edit the list at its position, created by [`DoublyLinkedList::cursor_front_mut()`] or [`DoublyLinkedList::cursor_back_mut()`].
+/// Every edit made through a [`CursorMut`] is `O(1)`.
+pub struct CursorMut<'a, T> {
+    /// [`Node`] the cursor is pointing at, `None` when pointing at the ghost position.
+    current: Option<NonNull<Node<T>>>,
+
+    /// Index of `current`, equal to the list's `len` when pointing at the ghost position.
+    index: usize,
+
+    /// [`DoublyLinkedList`] the cursor is mutably borrowing.
+    list: &'a mut DoublyLinkedList<T>,
+}
+
+
+impl<T> Iterator for IntoIter<T> {
     type Item = T;
 
     #[inline]
@@ -60,7 +126,7 @@ impl<T> Iterator for Iter<T> {
 }
 
 
-impl<T> DoubleEndedIterator for Iter<T> {
+impl<T> DoubleEndedIterator for IntoIter<T> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         return self.list.pop_back();
@@ -68,8 +134,534 @@ impl<T> DoubleEndedIterator for Iter<T> {
 }
 
 
-impl<T> FusedIterator for Iter<T> {  }
-impl<T> ExactSizeIterator for Iter<T> {  }
+impl<T> FusedIterator for IntoIter<T> {  }
+impl<T> ExactSizeIterator for IntoIter<T> {  }
+
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 { return None; }
+
+        return self.head.map(|ptr| {
+            let node = unsafe { &*ptr.as_ptr() };
+
+            self.head = node.next;
+            self.len -= 1;
+
+            &node.value
+        });
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.len, Some(self.len));
+    }
+}
+
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 { return None; }
+
+        return self.tail.map(|ptr| {
+            let node = unsafe { &*ptr.as_ptr() };
+
+            self.tail = node.prev;
+            self.len -= 1;
+
+            &node.value
+        });
+    }
+}
+
+
+impl<T> FusedIterator for Iter<'_, T> {  }
+impl<T> ExactSizeIterator for Iter<'_, T> {  }
+
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 { return None; }
+
+        return self.head.map(|ptr| {
+            let node = unsafe { &mut *ptr.as_ptr() };
+
+            self.head = node.next;
+            self.len -= 1;
+
+            &mut node.value
+        });
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.len, Some(self.len));
+    }
+}
+
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 { return None; }
+
+        return self.tail.map(|ptr| {
+            let node = unsafe { &mut *ptr.as_ptr() };
+
+            self.tail = node.prev;
+            self.len -= 1;
+
+            &mut node.value
+        });
+    }
+}
+
+
+impl<T> FusedIterator for IterMut<'_, T> {  }
+impl<T> ExactSizeIterator for IterMut<'_, T> {  }
+
+
+impl<'a, T> Cursor<'a, T> {
+    /// Returns the index of the [`Node`] the cursor is pointing at, or `None` when pointing at the ghost position.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = dl_list![1, 2];
+    /// let mut cursor = list.cursor_back();
+    /// 
+    /// assert_eq!(cursor.index(), Some(1));
+    /// 
+    /// cursor.move_next();
+    /// assert_eq!(cursor.index(), None);
+    /// ```
+    #[inline]
+    pub fn index(&self) -> Option<usize> {
+        return self.current.map(|_| self.index);
+    }
+
+    /// Moves the cursor to the next [`Node`], moving onto the ghost position past the `back` of the list.
+    /// Moving from the ghost position moves the cursor to the `front` of the list.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = dl_list![1, 2];
+    /// let mut cursor = list.cursor_front();
+    /// 
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), Some(&2));
+    /// 
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), None);
+    /// ```
+    #[inline]
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(ptr) => {
+                self.current = unsafe { ptr.as_ref().next };
+                self.index += 1;
+            },
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            },
+        }
+    }
+
+    /// Moves the cursor to the previous [`Node`], moving onto the ghost position before the `front` of the list.
+    /// Moving from the ghost position moves the cursor to the `back` of the list.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = dl_list![1, 2];
+    /// let mut cursor = list.cursor_front();
+    /// 
+    /// cursor.move_prev();
+    /// assert_eq!(cursor.current(), None);
+    /// 
+    /// cursor.move_prev();
+    /// assert_eq!(cursor.current(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(ptr) => {
+                self.current = unsafe { ptr.as_ref().prev };
+                self.index = self.index.checked_sub(1).unwrap_or(self.list.len);
+            },
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            },
+        }
+    }
+
+    /// Returns a reference to the value the cursor is pointing at, or `None` when pointing at the ghost position.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = dl_list![1, 2];
+    /// let cursor = list.cursor_front();
+    /// 
+    /// assert_eq!(cursor.current(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn current(&self) -> Option<&'a T> {
+        return self.current.map(|ptr| unsafe { &(*ptr.as_ptr()).value });
+    }
+
+    /// Returns a reference to the value after the cursor, which is the `front` of the list when pointing at the ghost position.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = dl_list![1, 2];
+    /// let cursor = list.cursor_front();
+    /// 
+    /// assert_eq!(cursor.peek_next(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn peek_next(&self) -> Option<&'a T> {
+        let next = match self.current {
+            Some(ptr) => unsafe { ptr.as_ref().next },
+            None => self.list.head,
+        };
+
+        return next.map(|ptr| unsafe { &(*ptr.as_ptr()).value });
+    }
+
+    /// Returns a reference to the value before the cursor, which is the `back` of the list when pointing at the ghost position.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = dl_list![1, 2];
+    /// let cursor = list.cursor_back();
+    /// 
+    /// assert_eq!(cursor.peek_prev(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        let prev = match self.current {
+            Some(ptr) => unsafe { ptr.as_ref().prev },
+            None => self.list.tail,
+        };
+
+        return prev.map(|ptr| unsafe { &(*ptr.as_ptr()).value });
+    }
+}
+
+
+impl<'a, T> CursorMut<'a, T> {
+    /// Returns the index of the [`Node`] the cursor is pointing at, or `None` when pointing at the ghost position.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2];
+    /// let cursor = list.cursor_back_mut();
+    /// 
+    /// assert_eq!(cursor.index(), Some(1));
+    /// ```
+    #[inline]
+    pub fn index(&self) -> Option<usize> {
+        return self.current.map(|_| self.index);
+    }
+
+    /// Moves the cursor to the next [`Node`], moving onto the ghost position past the `back` of the list.
+    /// Moving from the ghost position moves the cursor to the `front` of the list.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2];
+    /// let mut cursor = list.cursor_front_mut();
+    /// 
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), Some(&mut 2));
+    /// ```
+    #[inline]
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(ptr) => {
+                self.current = unsafe { ptr.as_ref().next };
+                self.index += 1;
+            },
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            },
+        }
+    }
+
+    /// Moves the cursor to the previous [`Node`], moving onto the ghost position before the `front` of the list.
+    /// Moving from the ghost position moves the cursor to the `back` of the list.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2];
+    /// let mut cursor = list.cursor_back_mut();
+    /// 
+    /// cursor.move_prev();
+    /// assert_eq!(cursor.current(), Some(&mut 1));
+    /// ```
+    #[inline]
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(ptr) => {
+                self.current = unsafe { ptr.as_ref().prev };
+                self.index = self.index.checked_sub(1).unwrap_or(self.list.len);
+            },
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            },
+        }
+    }
+
+    /// Returns a mutable reference to the value the cursor is pointing at, or `None` when pointing at the ghost position.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2];
+    /// let mut cursor = list.cursor_front_mut();
+    /// 
+    /// *cursor.current().unwrap() = 5;
+    /// assert_eq!(list, dl_list![5, 2]);
+    /// ```
+    #[inline]
+    pub fn current(&mut self) -> Option<&mut T> {
+        return self.current.map(|ptr| unsafe { &mut (*ptr.as_ptr()).value });
+    }
+
+    /// Returns a mutable reference to the value after the cursor, which is the `front` of the list when pointing at the ghost position.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2];
+    /// let mut cursor = list.cursor_front_mut();
+    /// 
+    /// assert_eq!(cursor.peek_next(), Some(&mut 2));
+    /// ```
+    #[inline]
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = match self.current {
+            Some(ptr) => unsafe { ptr.as_ref().next },
+            None => self.list.head,
+        };
+
+        return next.map(|ptr| unsafe { &mut (*ptr.as_ptr()).value });
+    }
+
+    /// Returns a mutable reference to the value before the cursor, which is the `back` of the list when pointing at the ghost position.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2];
+    /// let mut cursor = list.cursor_back_mut();
+    /// 
+    /// assert_eq!(cursor.peek_prev(), Some(&mut 1));
+    /// ```
+    #[inline]
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let prev = match self.current {
+            Some(ptr) => unsafe { ptr.as_ref().prev },
+            None => self.list.tail,
+        };
+
+        return prev.map(|ptr| unsafe { &mut (*ptr.as_ptr()).value });
+    }
+
+    /// Returns a read-only [`Cursor`] pointing at the same position, borrowing the [`CursorMut`] for its lifetime.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2];
+    /// let cursor = list.cursor_front_mut();
+    /// 
+    /// assert_eq!(cursor.as_cursor().current(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn as_cursor(&self) -> Cursor<'_, T> {
+        return Cursor {
+            current: self.current,
+            index: self.index,
+            list: self.list,
+        };
+    }
+
+    /// Inserts a new [`Node`] with the coresponding `value` after the cursor, without moving it.
+    /// When pointing at the ghost position the value is inserted at the `front` of the list.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 3];
+    /// let mut cursor = list.cursor_front_mut();
+    /// 
+    /// cursor.insert_after(2);
+    /// assert_eq!(list, dl_list![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn insert_after(&mut self, value: T) {
+        let node = Node::new(value).into_non_null();
+        let next = match self.current {
+            Some(ptr) => unsafe { ptr.as_ref().next },
+            None => self.list.head,
+        };
+
+        unsafe { self.list.splice_nodes(self.current, next, node, node, 1); }
+
+        if self.current.is_none() { self.index = self.list.len; }
+    }
+
+    /// Inserts a new [`Node`] with the coresponding `value` before the cursor, without moving it.
+    /// When pointing at the ghost position the value is inserted at the `back` of the list.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![2, 3];
+    /// let mut cursor = list.cursor_front_mut();
+    /// 
+    /// cursor.insert_before(1);
+    /// assert_eq!(cursor.index(), Some(1));
+    /// assert_eq!(list, dl_list![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn insert_before(&mut self, value: T) {
+        let node = Node::new(value).into_non_null();
+        let prev = match self.current {
+            Some(ptr) => unsafe { ptr.as_ref().prev },
+            None => self.list.tail,
+        };
+
+        unsafe { self.list.splice_nodes(prev, self.current, node, node, 1); }
+        self.index += 1;
+    }
+
+    /// Removes the [`Node`] the cursor is pointing at, returning its `value` field and moving the cursor to the next [`Node`].
+    /// Returns `None` when pointing at the ghost position.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2, 3];
+    /// let mut cursor = list.cursor_front_mut();
+    /// 
+    /// assert_eq!(cursor.remove_current(), Some(1));
+    /// assert_eq!(cursor.current(), Some(&mut 2));
+    /// assert_eq!(list, dl_list![2, 3]);
+    /// ```
+    #[inline]
+    pub fn remove_current(&mut self) -> Option<T> {
+        let ptr = self.current?;
+        self.current = unsafe { ptr.as_ref().next };
+
+        unsafe { self.list.unlink_node(ptr); }
+
+        // SAFETY: Every `Node` is allocated through `Node::into_non_null()`, and it was unlinked from the list above.
+        let node = unsafe { Box::from_raw(ptr.as_ptr()) };
+        return Some(node.value);
+    }
+
+    /// Moves every [`Node`] of `list` after the cursor, leaving the cursor in place.
+    /// When pointing at the ghost position the [`Node`]s are moved onto the `front` of the list.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 4];
+    /// let mut cursor = list.cursor_front_mut();
+    /// 
+    /// cursor.splice_after(dl_list![2, 3]);
+    /// assert_eq!(list, dl_list![1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn splice_after(&mut self, mut list: DoublyLinkedList<T>) {
+        let (head, tail) = match (list.head.take(), list.tail.take()) {
+            (Some(head), Some(tail)) => (head, tail),
+            _ => return,
+        };
+
+        let next = match self.current {
+            Some(ptr) => unsafe { ptr.as_ref().next },
+            None => self.list.head,
+        };
+
+        unsafe { self.list.splice_nodes(self.current, next, head, tail, list.len); }
+        list.len = 0;
+
+        if self.current.is_none() { self.index = self.list.len; }
+    }
+
+    /// Moves every [`Node`] of `list` before the cursor, leaving the cursor in place.
+    /// When pointing at the ghost position the [`Node`]s are moved onto the `back` of the list.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 4];
+    /// let mut cursor = list.cursor_back_mut();
+    /// 
+    /// cursor.splice_before(dl_list![2, 3]);
+    /// assert_eq!(cursor.index(), Some(3));
+    /// assert_eq!(list, dl_list![1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn splice_before(&mut self, mut list: DoublyLinkedList<T>) {
+        let (head, tail) = match (list.head.take(), list.tail.take()) {
+            (Some(head), Some(tail)) => (head, tail),
+            _ => return,
+        };
+
+        let prev = match self.current {
+            Some(ptr) => unsafe { ptr.as_ref().prev },
+            None => self.list.tail,
+        };
+
+        unsafe { self.list.splice_nodes(prev, self.current, head, tail, list.len); }
+
+        self.index += list.len;
+        list.len = 0;
+    }
+
+    /// Splits the list after the cursor, returning a new list holding every [`Node`] after it.
+    /// When pointing at the ghost position the entire list is returned.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2, 3];
+    /// let mut cursor = list.cursor_front_mut();
+    /// 
+    /// assert_eq!(cursor.split_after(), dl_list![2, 3]);
+    /// assert_eq!(list, dl_list![1]);
+    /// ```
+    #[inline]
+    pub fn split_after(&mut self) -> DoublyLinkedList<T> {
+        let at = if self.current.is_some() { self.index + 1 } else { 0 };
+        if self.current.is_none() { self.index = 0; }
+
+        return unsafe { self.list.split_off_after_node(self.current, at) };
+    }
+
+    /// Splits the list before the cursor, returning a new list holding every [`Node`] before it.
+    /// When pointing at the ghost position the entire list is returned.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2, 3];
+    /// let mut cursor = list.cursor_back_mut();
+    /// 
+    /// assert_eq!(cursor.split_before(), dl_list![1, 2]);
+    /// assert_eq!(cursor.index(), Some(0));
+    /// assert_eq!(list, dl_list![3]);
+    /// ```
+    #[inline]
+    pub fn split_before(&mut self) -> DoublyLinkedList<T> {
+        let at = self.index;
+        self.index = 0;
+
+        return unsafe { self.list.split_off_before_node(self.current, at) };
+    }
+}
 
 
 impl<T> DoublyLinkedList<T> {
@@ -109,10 +701,11 @@ impl<T> DoublyLinkedList<T> {
     /// ```
     #[inline]
     pub const fn is_empty(&self) -> bool {
-        return self.head.is_some();
+        return self.len == 0;
     }
 
     /// Clears the [`DoublyLinkedList`] settings its fields back to their default values.
+    /// Every [`Node`] previously within the list is dropped and deallocated.
     /// 
     /// ## Example
     /// ```rust
@@ -237,175 +830,575 @@ impl<T> DoublyLinkedList<T> {
     /// assert_eq!(list, dl_list![1, 2, 3]);
     /// ```
     #[inline]
-    pub fn push_back(&mut self, value: T) {
-        let mut new_node = Node::new(value);
-        new_node.prev = self.tail;
-        
-        let node_ptr = Some(new_node.into_non_null());
+    pub fn push_back(&mut self, value: T) {
+        let mut new_node = Node::new(value);
+        new_node.prev = self.tail;
+        
+        let node_ptr = Some(new_node.into_non_null());
+
+        match self.tail {
+            Some(mut ptr) => unsafe { ptr.as_mut().next = node_ptr; },
+            None => { self.head = node_ptr; },
+        }
+
+        self.len += 1;
+        self.tail = node_ptr;
+    }
+
+    /// Returns a double-ended [`Iterator`] over references to the values within the list.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = dl_list![1, 2, 3];
+    /// let mut iter = list.iter();
+    /// 
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next_back(), Some(&3));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        return Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        };
+    }
+
+    /// Returns a double-ended [`Iterator`] over mutable references to the values within the list.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2, 3];
+    /// 
+    /// for value in list.iter_mut() {
+    ///     *value *= 2;
+    /// }
+    /// 
+    /// assert_eq!(list, dl_list![2, 4, 6]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        return IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        };
+    }
+
+    /// Returns a [`Cursor`] pointing at the `front` of the list, or at the ghost position if the list is empty.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = dl_list![1, 2, 3];
+    /// let cursor = list.cursor_front();
+    /// 
+    /// assert_eq!(cursor.current(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        return Cursor {
+            current: self.head,
+            index: 0,
+            list: self,
+        };
+    }
+
+    /// Returns a [`Cursor`] pointing at the `back` of the list, or at the ghost position if the list is empty.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = dl_list![1, 2, 3];
+    /// let cursor = list.cursor_back();
+    /// 
+    /// assert_eq!(cursor.current(), Some(&3));
+    /// ```
+    #[inline]
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        return Cursor {
+            current: self.tail,
+            index: self.len.saturating_sub(1),
+            list: self,
+        };
+    }
+
+    /// Returns a [`CursorMut`] pointing at the `front` of the list, or at the ghost position if the list is empty.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2, 3];
+    /// let mut cursor = list.cursor_front_mut();
+    /// 
+    /// cursor.remove_current();
+    /// assert_eq!(list, dl_list![2, 3]);
+    /// ```
+    #[inline]
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        return CursorMut {
+            current: self.head,
+            index: 0,
+            list: self,
+        };
+    }
+
+    /// Returns a [`CursorMut`] pointing at the `back` of the list, or at the ghost position if the list is empty.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2, 3];
+    /// let mut cursor = list.cursor_back_mut();
+    /// 
+    /// cursor.insert_after(4);
+    /// assert_eq!(list, dl_list![1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        return CursorMut {
+            current: self.tail,
+            index: self.len.saturating_sub(1),
+            list: self,
+        };
+    }
+
+    /// Returns a reference to the [`Node`] at the given `index`, or `None` if `index` is out of bounds.
+    /// Traverses from whichever end is nearer to `index`, time complexity is `O(min(index, len - index))`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = dl_list![1, 2, 3];
+    /// 
+    /// assert_eq!(list.get(1), Some(&2));
+    /// ```
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len { return None; }
+
+        let ptr = self.node_at(index);
+        return Some(unsafe { &(*ptr.as_ptr()).value });
+    }
+
+    /// Returns a mutable reference to the [`Node`] at the given `index`, or `None` if `index` is out of bounds.
+    /// Traverses from whichever end is nearer to `index`, time complexity is `O(min(index, len - index))`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2, 3];
+    /// 
+    /// assert_eq!(list.get_mut(1), Some(&mut 2));
+    /// ```
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len { return None; }
+
+        let ptr = self.node_at(index);
+        return Some(unsafe { &mut (*ptr.as_ptr()).value });
+    }
+
+    /// Removes the list's `head` [`Node`], returning its `value`.
+    /// Time complexity is `O(1)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2, 3];
+    /// let pop = list.pop_front();
+    /// 
+    /// assert_eq!(pop, Some(1));
+    /// assert_eq!(list, dl_list![2, 3]);
+    /// ```
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        if let Some(ptr) = self.head {
+            // SAFETY: Every `Node` is allocated through `Node::into_non_null()`, so the `Box` can be reclaimed.
+            let node = unsafe { Box::from_raw(ptr.as_ptr()) };
+            self.head = node.next;
+
+            match self.head {
+                Some(mut ptr) => unsafe { ptr.as_mut().prev = None; },
+                None => { self.tail = None; },
+            }
+
+            self.len -= 1;
+            return Some(node.value);
+        }
+
+        return None;
+    }
+
+    /// Removes the list's `tail` [`Node`], returning its `value`.
+    /// Time complexity is `O(1)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2, 3];
+    /// let pop = list.pop_back();
+    /// 
+    /// assert_eq!(pop, Some(3));
+    /// assert_eq!(list, dl_list![1, 2]);
+    /// ```
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> {
+        if let Some(ptr) = self.tail {
+            // SAFETY: Every `Node` is allocated through `Node::into_non_null()`, so the `Box` can be reclaimed.
+            let node = unsafe { Box::from_raw(ptr.as_ptr()) };
+            self.tail = node.prev;
+
+            match self.tail {
+                Some(mut ptr) => unsafe { ptr.as_mut().next = None; },
+                None => { self.head = None; },
+            }
+
+            self.len -= 1;
+            return Some(node.value);
+        }
+
+        return None;
+    }
+
+    /// Removes the list's `head` [`Node`].
+    /// Time complexity is `O(1)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2, 3];
+    /// list.remove_front();
+    /// 
+    /// assert_eq!(list, dl_list![2, 3]);
+    /// ```
+    #[inline]
+    pub fn remove_front(&mut self) {
+        let _ = self.pop_front();
+    }
+
+    /// Removes the list's `tail` [`Node`].
+    /// Time complexity is `O(1)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2, 3];
+    /// list.remove_back();
+    /// 
+    /// assert_eq!(list, dl_list![1, 2]);
+    /// ```
+    #[inline]
+    pub fn remove_back(&mut self) {
+        let _ = self.pop_back();
+    }
+
+    /// Inserts a new [`Node`] with the coresponding `value` at `index`, relinking the [`Node`]s around it.
+    /// Traverses from whichever end is nearer to `index`, time complexity is `O(min(index, len - index))`.
+    /// 
+    /// ## Panics
+    /// Panics if `index` is greater than the list's `len`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 3];
+    /// list.insert(1, 2);
+    /// 
+    /// assert_eq!(list, dl_list![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, index: usize, value: T) {
+        if index > self.len { panic!("Index '{}' out of bounds.", index); }
+        if index == self.len { return self.push_back(value); }
+
+        let next = self.node_at(index);
+        let node = Node::new(value).into_non_null();
+
+        unsafe { self.splice_nodes(next.as_ref().prev, Some(next), node, node, 1); }
+    }
+
+    /// Removes the [`Node`] at `index`, relinking the [`Node`]s around it and returning its `value` field.
+    /// Returns `None` if `index` is out of bounds, time complexity is `O(min(index, len - index))`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = dl_list![1, 2, 3];
+    /// 
+    /// assert_eq!(list.remove(1), Some(2));
+    /// assert_eq!(list.remove(2), None);
+    /// assert_eq!(list, dl_list![1, 3]);
+    /// ```
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len { return None; }
 
-        match self.tail {
-            Some(mut ptr) => unsafe { ptr.as_mut().next = node_ptr; },
-            None => { self.head = node_ptr; },
-        }
+        let ptr = self.node_at(index);
+        unsafe { self.unlink_node(ptr); }
 
-        self.len += 1;
-        self.tail = node_ptr;
+        // SAFETY: Every `Node` is allocated through `Node::into_non_null()`, and it was unlinked from the list above.
+        let node = unsafe { Box::from_raw(ptr.as_ptr()) };
+        return Some(node.value);
     }
 
-    /// Returns a reference to the [`Node`] at the given `index`.
-    /// Time complexity is `O(n)`.
+    /// Retains only the values for which `predicate` returns `true`, unlinking and freeing every other [`Node`] in a single traversal.
+    /// The relative order of the retained values is preserved, time complexity is `O(n)`.
     /// 
     /// ## Example
     /// ```rust
-    /// let list = dl_list![1, 2, 3];
+    /// let mut list = dl_list![1, 2, 3, 4, 5];
+    /// list.retain(|x| x % 2 == 1);
     /// 
-    /// assert_eq!(list.get(1), Some(&2));
+    /// assert_eq!(list, dl_list![1, 3, 5]);
     /// ```
     #[inline]
-    pub fn get(&self, index: usize) -> Option<&T> {
-        if index == 0 { return self.front(); }
-        if index == self.len - 1 { return self.back(); }
-
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut predicate: F) {
         let mut current = self.head;
-        let mut i = 0;
 
         while let Some(ptr) = current {
-            let node = unsafe { ptr.as_ref() };
+            current = unsafe { ptr.as_ref().next };
 
-            if i == index {
-                return Some(&node.value);
-            }
+            if predicate(unsafe { &ptr.as_ref().value }) { continue; }
 
-            current = node.next;
-            i += 1;
-        }
+            unsafe { self.unlink_node(ptr); }
 
-        return None;
+            // SAFETY: Every `Node` is allocated through `Node::into_non_null()`, and it was unlinked from the list above.
+            drop(unsafe { Box::from_raw(ptr.as_ptr()) });
+        }
     }
 
-    /// Returns a mutable reference to the [`Node`] at the given `index`.
-    /// Time complexity is `O(n)`.
+    /// Splits the list at `at`, returning a new list holding the [`Node`]s from `at` onwards.
+    /// The chain is cut in place and no [`Node`]s are reallocated, time complexity is `O(min(at, len - at))`.
+    /// 
+    /// ## Panics
+    /// Panics if `at` is greater than the list's `len`.
     /// 
     /// ## Example
     /// ```rust
-    /// let mut list = dl_list![1, 2, 3];
+    /// let mut list = dl_list![1, 2, 3, 4];
+    /// let tail = list.split_off(1);
     /// 
-    /// assert_eq!(list.get_mut(1), Some(&mut 2));
+    /// assert_eq!(list, dl_list![1]);
+    /// assert_eq!(tail, dl_list![2, 3, 4]);
     /// ```
     #[inline]
-    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        if index == 0 { return self.front_mut(); }
-        if index == self.len - 1 { return self.back_mut(); }
-
-        let mut current = self.head;
-        let mut i = 0;
-
-        while let Some(mut ptr) = current {
-            let node = unsafe { ptr.as_mut() };
-
-            if i == index {
-                return Some(&mut node.value);
-            }
-
-            current = node.next;
-            i += 1;
-        }
+    pub fn split_off(&mut self, at: usize) -> Self {
+        if at > self.len { panic!("Index '{}' out of bounds.", at); }
+        if at == 0 { return take(self); }
 
-        return None;
+        let last = self.node_at(at - 1);
+        return unsafe { self.split_off_after_node(Some(last), at) };
     }
 
-    /// Removes the list's `head` [`Node`], returning its `value`.
-    /// Time complexity is `O(1)`.
+    /// Consumes the list, splitting it into the values for which `predicate` returns `true` and those for which it returns `false`.
+    /// The relative order of the values is preserved within both lists, [`Node`]s are relinked rather than reallocated and time complexity is `O(n)`.
     /// 
     /// ## Example
     /// ```rust
-    /// let mut list = dl_list![1, 2, 3];
-    /// let pop = list.pop_front();
+    /// let (evens, odds) = dl_list![1, 2, 3, 4].partition(|x| x % 2 == 0);
     /// 
-    /// assert_eq!(pop, Some(1));
-    /// assert_eq!(list, dl_list![2, 3]);
+    /// assert_eq!(evens, dl_list![2, 4]);
+    /// assert_eq!(odds, dl_list![1, 3]);
     /// ```
     #[inline]
-    pub fn pop_front(&mut self) -> Option<T> {
-        if let Some(mut ptr) = self.head {
-            let value;
+    pub fn partition<P: FnMut(&T) -> bool>(mut self, mut predicate: P) -> (Self, Self) {
+        let mut matching = Self::new();
+        let mut rest = Self::new();
 
-            unsafe {
-                value = ptr_read(&mut (*ptr.as_mut()).value);
-                self.head = (*self.head.unwrap().as_ptr()).next;
+        while let Some(mut ptr) = self.head {
+            let node = unsafe { ptr.as_mut() };
+            let list = if predicate(&node.value) { &mut matching } else { &mut rest };
 
-                if let Some(ptr) = self.head {
-                    (*ptr.as_ptr()).prev = None;
-                }
+            self.head = node.next.take();
+            self.len -= 1;
+
+            node.prev = list.tail;
+
+            match list.tail {
+                Some(mut tail) => unsafe { tail.as_mut().next = Some(ptr); },
+                None => { list.head = Some(ptr); },
             }
 
-            self.len -= 1;
-            return Some(value);
+            list.tail = Some(ptr);
+            list.len += 1;
         }
 
-        return None;
+        self.tail = None;
+
+        return (matching, rest);
     }
 
-    /// Removes the list's `tail` [`Node`], returning its `value`.
-    /// Time complexity is `O(1)`.
+    /// Returns the index of the first value within the list for which `predicate` returns `true`.
+    /// Time complexity is `O(n)`.
     /// 
     /// ## Example
     /// ```rust
-    /// let mut list = dl_list![1, 2, 3];
-    /// let pop = list.pop_back();
+    /// let list = dl_list![1, 2, 3, 4];
     /// 
-    /// assert_eq!(pop, Some(3));
-    /// assert_eq!(list, dl_list![1, 2]);
+    /// assert_eq!(list.position(|x| x % 2 == 0), Some(1));
+    /// assert_eq!(list.position(|x| *x > 4), None);
     /// ```
     #[inline]
-    pub fn pop_back(&mut self) -> Option<T> {
-        if let Some(mut ptr) = self.tail {
-            let value;
-
-            unsafe {
-                value = ptr_read(&mut (*ptr.as_mut()).value);
-                self.tail = (*self.tail.unwrap().as_ptr()).prev;
-
-                if let Some(ptr) = self.tail {
-                    (*ptr.as_ptr()).next = None;
-                }
-            }
-
-            self.len -= 1;
-            return Some(value);
-        }
-
-        return None;
+    pub fn position<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<usize> {
+        return self.iter().position(predicate);
     }
 
-    /// Removes the list's `head` [`Node`].
-    /// Time complexity is `O(1)`.
+    /// Returns a reference to the first value within the list for which `predicate` returns `true`.
+    /// Time complexity is `O(n)`.
     /// 
     /// ## Example
     /// ```rust
-    /// let mut list = dl_list![1, 2, 3];
-    /// list.remove_front();
+    /// let list = dl_list![1, 2, 3, 4];
     /// 
-    /// assert_eq!(list, dl_list![2, 3]);
+    /// assert_eq!(list.find(|x| x % 2 == 0), Some(&2));
+    /// assert_eq!(list.find(|x| *x > 4), None);
     /// ```
     #[inline]
-    pub fn remove_front(&mut self) {
-        let _ = self.pop_front();
+    pub fn find<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<&T> {
+        return self.iter().find(|x| predicate(x));
     }
 
-    /// Removes the list's `tail` [`Node`].
-    /// Time complexity is `O(1)`.
+    /// Returns a reference to the last value within the list for which `predicate` returns `true`.
+    /// Time complexity is `O(n)`, searching from the `back`.
     /// 
     /// ## Example
     /// ```rust
-    /// let mut list = dl_list![1, 2, 3];
-    /// list.remove_back();
+    /// let list = dl_list![1, 2, 3, 4];
     /// 
-    /// assert_eq!(list, dl_list![1, 2]);
+    /// assert_eq!(list.rfind(|x| x % 2 == 0), Some(&4));
+    /// assert_eq!(list.rfind(|x| *x > 4), None);
     /// ```
     #[inline]
-    pub fn remove_back(&mut self) {
-        let _ = self.pop_back();
+    pub fn rfind<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<&T> {
+        return self.iter().rev().find(|x| predicate(x));
+    }
+
+    /// Returns a pointer to the [`Node`] at `index`, which must be less than the list's `len`.
+    /// Traverses from the `back` when `index` is within the second half of the list.
+    #[inline]
+    fn node_at(&self, index: usize) -> NonNull<Node<T>> {
+        debug_assert!(index < self.len);
+
+        let current = if index < self.len / 2 {
+            let mut current = self.head;
+            for _ in 0..index { current = unsafe { current.and_then(|ptr| ptr.as_ref().next) }; }
+
+            current
+        } else {
+            let mut current = self.tail;
+            for _ in index..self.len - 1 { current = unsafe { current.and_then(|ptr| ptr.as_ref().prev) }; }
+
+            current
+        };
+
+        return current.unwrap_or_else(|| panic!("Index '{}' out of bounds.", index));
+    }
+
+    /// Links the chain of `len` [`Node`]s from `first` to `last` between `prev` and `next`, which must be adjacent within the list.
+    /// 
+    /// ## Safety
+    /// - `first` through `last` must be a valid chain of `len` [`Node`]s not already owned by any list.
+    #[inline]
+    unsafe fn splice_nodes(
+        &mut self,
+        prev: Option<NonNull<Node<T>>>,
+        next: Option<NonNull<Node<T>>>,
+        mut first: NonNull<Node<T>>,
+        mut last: NonNull<Node<T>>,
+        len: usize,
+    ) {
+        match prev {
+            Some(mut prev) => { prev.as_mut().next = Some(first); },
+            None => { self.head = Some(first); },
+        }
+
+        match next {
+            Some(mut next) => { next.as_mut().prev = Some(last); },
+            None => { self.tail = Some(last); },
+        }
+
+        first.as_mut().prev = prev;
+        last.as_mut().next = next;
+
+        self.len += len;
+    }
+
+    /// Unlinks `node` from the list, relinking its neighbours without freeing it.
+    /// 
+    /// ## Safety
+    /// - `node` must be a [`Node`] owned by the list.
+    #[inline]
+    unsafe fn unlink_node(&mut self, mut node: NonNull<Node<T>>) {
+        let node = node.as_mut();
+
+        match node.prev {
+            Some(mut prev) => { prev.as_mut().next = node.next; },
+            None => { self.head = node.next; },
+        }
+
+        match node.next {
+            Some(mut next) => { next.as_mut().prev = node.prev; },
+            None => { self.tail = node.prev; },
+        }
+
+        node.prev = None;
+        node.next = None;
+        self.len -= 1;
+    }
+
+    /// Splits the list after `node`, which sits at index `at - 1`, returning the [`Node`]s after it as a new list.
+    /// The entire list is returned if `node` is `None`.
+    /// 
+    /// ## Safety
+    /// - `node` must be a [`Node`] owned by the list, and `at` must be its index plus one.
+    #[inline]
+    unsafe fn split_off_after_node(&mut self, node: Option<NonNull<Node<T>>>, at: usize) -> Self {
+        let mut node = match node {
+            Some(node) => node,
+            None => return take(self),
+        };
+
+        let split = match node.as_mut().next.take() {
+            Some(mut head) => {
+                head.as_mut().prev = None;
+
+                Self {
+                    head: Some(head),
+                    tail: self.tail,
+                    len: self.len - at,
+                }
+            },
+            None => Self::new(),
+        };
+
+        self.tail = Some(node);
+        self.len = at;
+
+        return split;
+    }
+
+    /// Splits the list before `node`, which sits at index `at`, returning the [`Node`]s before it as a new list.
+    /// The entire list is returned if `node` is `None`.
+    /// 
+    /// ## Safety
+    /// - `node` must be a [`Node`] owned by the list, and `at` must be its index.
+    #[inline]
+    unsafe fn split_off_before_node(&mut self, node: Option<NonNull<Node<T>>>, at: usize) -> Self {
+        let mut node = match node {
+            Some(node) => node,
+            None => return take(self),
+        };
+
+        let split = match node.as_mut().prev.take() {
+            Some(mut tail) => {
+                tail.as_mut().next = None;
+
+                Self {
+                    head: self.head,
+                    tail: Some(tail),
+                    len: at,
+                }
+            },
+            None => Self::new(),
+        };
+
+        self.head = Some(node);
+        self.len -= at;
+
+        return split;
     }
 }
 
@@ -434,6 +1427,79 @@ impl<T: PartialEq> PartialEq for DoublyLinkedList<T> {
 impl<T: Eq> Eq for DoublyLinkedList<T> {  }
 
 
+impl<T: PartialEq> PartialEq<[T]> for DoublyLinkedList<T> {
+    #[inline]
+    fn eq(&self, other: &[T]) -> bool {
+        return self.len == other.len() && self.iter().eq(other.iter());
+    }
+}
+
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for DoublyLinkedList<T> {
+    #[inline]
+    fn eq(&self, other: &[T; N]) -> bool {
+        return *self == other[..];
+    }
+}
+
+
+impl<T: PartialEq> PartialEq<Vec<T>> for DoublyLinkedList<T> {
+    #[inline]
+    fn eq(&self, other: &Vec<T>) -> bool {
+        return *self == other[..];
+    }
+}
+
+
+impl<T: PartialEq> PartialEq<DoublyLinkedList<T>> for [T] {
+    #[inline]
+    fn eq(&self, other: &DoublyLinkedList<T>) -> bool {
+        return *other == *self;
+    }
+}
+
+
+impl<T: PartialEq, const N: usize> PartialEq<DoublyLinkedList<T>> for [T; N] {
+    #[inline]
+    fn eq(&self, other: &DoublyLinkedList<T>) -> bool {
+        return *other == self[..];
+    }
+}
+
+
+impl<T: PartialEq> PartialEq<DoublyLinkedList<T>> for Vec<T> {
+    #[inline]
+    fn eq(&self, other: &DoublyLinkedList<T>) -> bool {
+        return *other == self[..];
+    }
+}
+
+
+impl<T: PartialOrd> PartialOrd for DoublyLinkedList<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return self.iter().partial_cmp(other.iter());
+    }
+}
+
+
+impl<T: Ord> Ord for DoublyLinkedList<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        return self.iter().cmp(other.iter());
+    }
+}
+
+
+impl<T: Hash> Hash for DoublyLinkedList<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for value in self.iter() { value.hash(state); }
+    }
+}
+
+
 impl<T: fmt::Debug> fmt::Debug for DoublyLinkedList<T> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -455,8 +1521,8 @@ impl<T: fmt::Display> fmt::Display for DoublyLinkedList<T> {
 
         let mut result = String::from("[");
 
-        for e in self.into_iter() {
-            result.push_str(format!("{}", e).as_str());
+        for e in self.iter() {
+            result.push_str(format!("{}, ", e).as_str());
         }
 
         return write!(f, "{}", result.strip_suffix(", ").unwrap().to_string() + "]");
@@ -464,13 +1530,68 @@ impl<T: fmt::Display> fmt::Display for DoublyLinkedList<T> {
 }
 
 
-impl<T> Copy for DoublyLinkedList<T> {  }
+impl<T> Drop for DoublyLinkedList<T> {
+    #[inline]
+    fn drop(&mut self) {
+        let mut current = self.head;
+
+        while let Some(ptr) = current {
+            // SAFETY: Every `Node` is allocated through `Node::into_non_null()` and is only reachable once through `next`.
+            let node = unsafe { Box::from_raw(ptr.as_ptr()) };
+            current = node.next;
+        }
+    }
+}
+
+
+// SAFETY: The list uniquely owns its `Node`s, so sending it is equivalent to sending every `T` within.
+unsafe impl<T: Send> Send for DoublyLinkedList<T> {  }
+
+// SAFETY: Shared access to the list only ever hands out `&T`, so it is safe to share whenever `T` is.
+unsafe impl<T: Sync> Sync for DoublyLinkedList<T> {  }
+
+// SAFETY: `Iter` behaves like a `&T` for each value within the list.
+unsafe impl<T: Sync> Send for Iter<'_, T> {  }
+unsafe impl<T: Sync> Sync for Iter<'_, T> {  }
+
+// SAFETY: `IterMut` behaves like a `&mut T` for each value within the list.
+unsafe impl<T: Send> Send for IterMut<'_, T> {  }
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {  }
+
+// SAFETY: `Cursor` behaves like a `&T` for each value within the list.
+unsafe impl<T: Sync> Send for Cursor<'_, T> {  }
+unsafe impl<T: Sync> Sync for Cursor<'_, T> {  }
+
+// SAFETY: `CursorMut` behaves like a `&mut T` for each value within the list.
+unsafe impl<T: Send> Send for CursorMut<'_, T> {  }
+unsafe impl<T: Sync> Sync for CursorMut<'_, T> {  }
 
 
-impl<T> Clone for DoublyLinkedList<T> {
+impl<T> Default for DoublyLinkedList<T> {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+
+impl<T: Clone> Clone for DoublyLinkedList<T> {
     #[inline]
     fn clone(&self) -> Self {
-        return *self;
+        return self.iter().cloned().collect();
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        while self.len > source.len { self.remove_back(); }
+
+        let mut iter = source.iter();
+
+        for (value, source) in self.iter_mut().zip(&mut iter) {
+            value.clone_from(source);
+        }
+
+        for value in iter { self.push_back(value.clone()); }
     }
 }
 
@@ -481,7 +1602,7 @@ impl<T> Index<usize> for DoublyLinkedList<T> {
     #[inline]
     fn index(&self, index: usize) -> &Self::Output {
         return self.get(index)
-            .expect(format!("Index '{}' out of bounds", index).as_str());
+            .unwrap_or_else(|| panic!("Index '{}' out of bounds", index));
     }
 }
 
@@ -490,18 +1611,110 @@ impl<T> IndexMut<usize> for DoublyLinkedList<T> {
     #[inline]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         return self.get_mut(index)
-            .expect(format!("Index '{}' out of bounds", index).as_str());
+            .unwrap_or_else(|| panic!("Index '{}' out of bounds", index));
+    }
+}
+
+
+impl<T> Add for DoublyLinkedList<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        return self;
+    }
+}
+
+
+impl<T> AddAssign for DoublyLinkedList<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        match self.tail {
+            Some(mut tail) => if let Some(mut head) = rhs.head {
+                unsafe {
+                    tail.as_mut().next = Some(head);
+                    head.as_mut().prev = Some(tail);
+                }
+
+                self.tail = rhs.tail;
+            },
+
+            None => {
+                self.head = rhs.head;
+                self.tail = rhs.tail;
+            },
+        }
+
+        self.len += rhs.len;
+
+        // The `Node`s of `rhs` are now owned by `self`.
+        forget(rhs);
     }
 }
 
 
 impl<T> IntoIterator for DoublyLinkedList<T> {
     type Item = T;
-    type IntoIter = Iter<T>;
+    type IntoIter = IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        return IntoIter { list: self };
+    }
+}
+
+
+impl<'a, T> IntoIterator for &'a DoublyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter();
+    }
+}
+
+
+impl<'a, T> IntoIterator for &'a mut DoublyLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        return Iter { list: self };
+        return self.iter_mut();
+    }
+}
+
+
+impl<T> From<SinglyLinkedList<T>> for DoublyLinkedList<T> {
+    #[inline]
+    fn from(list: SinglyLinkedList<T>) -> Self {
+        return list.into_iter().collect();
+    }
+}
+
+
+impl<T> From<List<T>> for DoublyLinkedList<T> {
+    #[inline]
+    fn from(list: List<T>) -> Self {
+        return list.into_iter().collect();
+    }
+}
+
+
+impl<T, const N: usize> From<[T; N]> for DoublyLinkedList<T> {
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        return IntoIterator::into_iter(array).collect();
+    }
+}
+
+
+impl<T> Extend<T> for DoublyLinkedList<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter { self.push_back(value); }
     }
 }
 
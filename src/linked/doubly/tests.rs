@@ -1,4 +1,7 @@
 use std::iter::FromIterator;
+use std::rc::Rc;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 use super::super::super::dl_list;
 use super::DoublyLinkedList;
@@ -114,6 +117,7 @@ fn index() {
 
 
 #[test]
+#[allow(clippy::useless_vec)]
 fn from_iter() {
     let arr = [1, 2, 3];
     let vec = vec![1, 2, 3];
@@ -133,4 +137,491 @@ fn node_into() {
     
     assert_eq!(boxed.value, 5);
     assert_eq!(unsafe { ptr.as_ref().value }, 5);
-}
\ No newline at end of file
+
+    drop(unsafe { Box::from_raw(ptr.as_ptr()) });
+}
+
+#[test]
+fn add() {
+    let list = dl_list![1, 2] + dl_list![3, 4];
+    assert_eq!(list, dl_list![1, 2, 3, 4]);
+    assert_eq!(list.back(), Some(&4));
+
+    let mut list = DoublyLinkedList::new();
+    list += dl_list![1];
+    list += DoublyLinkedList::new();
+    list += dl_list![2, 3];
+
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.pop_back(), Some(3));
+    assert_eq!(list, dl_list![1, 2]);
+}
+
+
+#[test]
+fn iter_ref() {
+    let list = dl_list![1, 2, 3, 4];
+    let mut iter = list.iter();
+
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next_back(), Some(&4));
+    assert_eq!(iter.next_back(), Some(&3));
+    assert_eq!(iter.next(), Some(&2));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+    assert_eq!(list.len(), 4);
+}
+
+
+#[test]
+fn iter_mut() {
+    let mut list = dl_list![1, 2, 3];
+
+    for value in list.iter_mut().rev() {
+        *value *= 10;
+    }
+
+    assert_eq!(list, dl_list![10, 20, 30]);
+}
+
+
+#[test]
+fn display() {
+    let list = dl_list![1, 2, 3];
+    assert_eq!(format!("{}", list), "[1, 2, 3]");
+    assert_eq!(list.len(), 3);
+}
+
+
+#[test]
+fn into_iter_ref() {
+    let mut list = dl_list![1, 2, 3];
+
+    for value in &mut list {
+        *value += 1;
+    }
+
+    let mut sum = 0;
+
+    for value in &list {
+        sum += *value;
+    }
+
+    assert_eq!(sum, 9);
+    assert_eq!(list, dl_list![2, 3, 4]);
+}
+
+
+#[test]
+fn drop_clear() {
+    let value = Rc::new(0);
+    let mut list = dl_list![value.clone(), value.clone(), value.clone()];
+
+    assert_eq!(Rc::strong_count(&value), 4);
+
+    list.clear();
+    assert!(list.is_empty());
+    assert_eq!(list.back(), None);
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    list.push_back(value.clone());
+    list.push_front(value.clone());
+    assert_eq!(list.pop_back(), Some(value.clone()));
+    assert_eq!(Rc::strong_count(&value), 2);
+
+    drop(list);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+
+#[test]
+fn clone() {
+    let list = dl_list![1, 2, 3];
+    let mut cloned = list.clone();
+
+    *cloned.back_mut().unwrap() = 4;
+
+    assert_eq!(list, dl_list![1, 2, 3]);
+    assert_eq!(cloned, dl_list![1, 2, 4]);
+    assert_eq!(cloned.pop_back(), Some(4));
+    assert_eq!(list.back(), Some(&3));
+}
+
+
+#[test]
+fn clone_from() {
+    let mut list = dl_list![1, 2, 3, 4];
+    let head = list.head;
+
+    list.clone_from(&dl_list![5, 6]);
+    assert_eq!(list, dl_list![5, 6]);
+    assert_eq!(list.back(), Some(&6));
+    assert_eq!(list.head, head);
+
+    list.clone_from(&dl_list![7, 8, 9]);
+    assert_eq!(list, dl_list![7, 8, 9]);
+    assert_eq!(list.back(), Some(&9));
+    assert_eq!(list.head, head);
+}
+
+
+#[test]
+fn default() {
+    let list = DoublyLinkedList::<i32>::default();
+
+    assert!(list.is_empty());
+    assert_eq!(list.front(), None);
+    assert_eq!(list.back(), None);
+}
+
+
+fn hash_of<H: Hash>(value: &H) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    return hasher.finish();
+}
+
+
+#[test]
+fn hash() {
+    assert_eq!(hash_of(&dl_list![1, 2, 3]), hash_of(&dl_list![1, 2, 3]));
+    assert_ne!(hash_of(&dl_list![1, 2, 3]), hash_of(&dl_list![3, 2, 1]));
+    assert_ne!(hash_of(&dl_list![vec![1], vec![2, 3]]), hash_of(&dl_list![vec![1, 2], vec![3]]));
+}
+
+
+#[test]
+fn ord() {
+    assert!(dl_list![1, 2, 3] < dl_list![1, 2, 4]);
+    assert!(dl_list![1, 2] < dl_list![1, 2, 3]);
+    assert!(dl_list![2] > dl_list![1, 9, 9]);
+    assert_eq!(dl_list![1, 2].cmp(&dl_list![1, 2]), std::cmp::Ordering::Equal);
+    assert_eq!(dl_list![1.0, f64::NAN].partial_cmp(&dl_list![1.0, 2.0]), None);
+}
+
+
+#[test]
+fn extend() {
+    let mut list = dl_list![1, 2];
+
+    list.extend(vec![3, 4]);
+    list.extend(5 ..= 6);
+    list.extend(std::iter::empty());
+
+    assert_eq!(list, dl_list![1, 2, 3, 4, 5, 6]);
+    assert_eq!(list.len(), 6);
+}
+
+
+#[test]
+fn send_sync() {
+    fn assert_send_sync<S: Send + Sync>() {  }
+
+    assert_send_sync::<DoublyLinkedList<i32>>();
+    assert_send_sync::<super::Iter<'_, i32>>();
+    assert_send_sync::<super::IterMut<'_, i32>>();
+    assert_send_sync::<super::IntoIter<i32>>();
+
+    let list = dl_list![1, 2, 3];
+    let handle = std::thread::spawn(move || list.into_iter().sum::<i32>());
+
+    assert_eq!(handle.join().unwrap(), 6);
+}
+
+
+#[test]
+fn from_array() {
+    let list = DoublyLinkedList::from([String::from("a"), String::from("b"), String::from("c")]);
+
+    assert_eq!(list, dl_list![String::from("a"), String::from("b"), String::from("c")]);
+    assert_eq!(list.len(), 3);
+    assert!(DoublyLinkedList::<i32>::from([]).is_empty());
+}
+
+
+#[test]
+fn from_lists() {
+    use crate::{list, sl_list};
+
+    let list = DoublyLinkedList::from(list![1, 2, 3]);
+
+    assert_eq!(list, dl_list![1, 2, 3]);
+    assert_eq!(list.back(), Some(&3));
+    assert_eq!(DoublyLinkedList::from(sl_list![String::from("a")]), dl_list![String::from("a")]);
+}
+
+
+#[test]
+fn eq_foreign() {
+    let list = dl_list![1, 2, 3];
+
+    assert_eq!(list, [1, 2, 3]);
+    assert_eq!(list, vec![1, 2, 3]);
+    assert_eq!(list, [1, 2, 3][..]);
+    assert_eq!([1, 2, 3], list);
+    assert_eq!(vec![1, 2, 3], list);
+    assert_eq!([1, 2, 3][..], list);
+
+    assert_ne!(list, [1, 2]);
+    assert_ne!(list, vec![1, 2, 4]);
+    assert_ne!([3, 2, 1], list);
+}
+
+
+#[test]
+fn position_find() {
+    let list = dl_list![1, 2, 3, 4];
+
+    assert_eq!(list.position(|x| *x == 3), Some(2));
+    assert_eq!(list.find(|x| x % 2 == 0), Some(&2));
+    assert_eq!(list.rfind(|x| x % 2 == 0), Some(&4));
+    assert_eq!(list.position(|x| *x == 5), None);
+    assert_eq!(list.rfind(|x| *x == 5), None);
+}
+
+
+#[test]
+fn dl_list_macro_repeat() {
+    let list = dl_list![String::from("a"); 3];
+
+    assert_eq!(list, ["a", "a", "a"].map(String::from));
+    assert!(dl_list![0; 0].is_empty());
+    assert_eq!(dl_list![1, 2,], [1, 2]);
+}
+
+
+#[test]
+fn partition() {
+    let list = dl_list![String::from("a"), String::from("bb"), String::from("c"), String::from("dd")];
+    let (short, long) = list.partition(|x| x.len() == 1);
+
+    assert_eq!(short, ["a", "c"].map(String::from));
+    assert_eq!(long, ["bb", "dd"].map(String::from));
+    assert_eq!((short.len(), long.len()), (2, 2));
+
+    assert!(short.iter().rev().eq(["c", "a"].iter()));
+    assert!(long.iter().rev().eq(["dd", "bb"].iter()));
+
+    let (all, none) = dl_list![1, 2, 3].partition(|_| true);
+
+    assert_eq!(all, [1, 2, 3]);
+    assert!(none.is_empty() && none.front().is_none() && none.back().is_none());
+}
+
+
+#[test]
+fn cursor() {
+    let list = dl_list![1, 2, 3];
+    let mut cursor = list.cursor_front();
+
+    assert_eq!((cursor.index(), cursor.current(), cursor.peek_prev(), cursor.peek_next()), (Some(0), Some(&1), None, Some(&2)));
+
+    cursor.move_prev();
+    assert_eq!((cursor.index(), cursor.current(), cursor.peek_prev(), cursor.peek_next()), (None, None, Some(&3), Some(&1)));
+
+    cursor.move_prev();
+    assert_eq!((cursor.index(), cursor.current()), (Some(2), Some(&3)));
+
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&1)));
+
+    let cursor = list.cursor_back();
+    assert_eq!((cursor.index(), cursor.current()), (Some(2), Some(&3)));
+
+    let empty = DoublyLinkedList::<i32>::new();
+    let mut cursor = empty.cursor_back();
+
+    assert_eq!((cursor.index(), cursor.current()), (None, None));
+    cursor.move_prev();
+    assert_eq!((cursor.index(), cursor.current()), (None, None));
+}
+
+
+#[test]
+fn cursor_mut() {
+    let mut list = dl_list![String::from("b"), String::from("d")];
+    let mut cursor = list.cursor_front_mut();
+
+    cursor.insert_before(String::from("a"));
+    cursor.insert_after(String::from("c"));
+    assert_eq!(cursor.index(), Some(1));
+
+    cursor.current().unwrap().push('!');
+    cursor.move_prev();
+    cursor.move_prev();
+    cursor.insert_after(String::from("0"));
+    cursor.insert_before(String::from("e"));
+    assert_eq!(cursor.index(), None);
+
+    assert_eq!(cursor.remove_current(), None);
+    cursor.move_next();
+    assert_eq!(cursor.remove_current(), Some(String::from("0")));
+    assert_eq!(cursor.as_cursor().current(), Some(&String::from("a")));
+
+    assert_eq!(list, ["a", "b!", "c", "d", "e"].map(String::from));
+    assert!(list.iter().rev().eq(["e", "d", "c", "b!", "a"].iter()));
+
+    let mut cursor = list.cursor_back_mut();
+    assert_eq!(cursor.remove_current(), Some(String::from("e")));
+    assert_eq!((cursor.index(), cursor.current()), (None, None));
+
+    assert_eq!((list.len(), list.back()), (4, Some(&String::from("d"))));
+}
+
+
+#[test]
+fn cursor_splice() {
+    let mut list = dl_list![1, 5];
+    let mut cursor = list.cursor_front_mut();
+
+    cursor.splice_after(dl_list![2, 3]);
+    cursor.move_next();
+    cursor.move_next();
+    cursor.move_next();
+    cursor.splice_before(dl_list![4]);
+    cursor.splice_before(DoublyLinkedList::new());
+    assert_eq!((cursor.index(), cursor.current()), (Some(4), Some(&mut 5)));
+
+    cursor.move_next();
+    cursor.splice_after(dl_list![-1, 0]);
+    cursor.splice_before(dl_list![6]);
+    assert_eq!(cursor.index(), None);
+
+    assert_eq!(list, [-1, 0, 1, 2, 3, 4, 5, 6]);
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [6, 5, 4, 3, 2, 1, 0, -1]);
+}
+
+
+#[test]
+fn cursor_split() {
+    let mut list = dl_list![1, 2, 3, 4, 5];
+    let mut cursor = list.cursor_front_mut();
+
+    cursor.move_next();
+    cursor.move_next();
+
+    let back = cursor.split_after();
+    assert_eq!((back.len(), back.front(), back.back()), (2, Some(&4), Some(&5)));
+
+    let front = cursor.split_before();
+    assert_eq!((front.len(), front.front(), front.back()), (2, Some(&1), Some(&2)));
+    assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&mut 3)));
+
+    assert!(cursor.split_after().is_empty());
+    assert!(cursor.split_before().is_empty());
+
+    cursor.move_next();
+    let all = cursor.split_before();
+    assert_eq!(all, [3]);
+    assert_eq!(cursor.index(), None);
+
+    assert!(list.is_empty() && list.front().is_none() && list.back().is_none());
+    assert_eq!(back.iter().rev().copied().collect::<Vec<_>>(), [5, 4]);
+    assert_eq!(front.iter().rev().copied().collect::<Vec<_>>(), [2, 1]);
+}
+
+
+#[test]
+fn insert_remove() {
+    let mut list = DoublyLinkedList::from(["b", "e"].map(String::from));
+
+    list.insert(0, String::from("a"));
+    list.insert(2, String::from("c"));
+    list.insert(3, String::from("d"));
+    list.insert(5, String::from("f"));
+
+    assert_eq!(list, ["a", "b", "c", "d", "e", "f"].map(String::from));
+    assert!(list.iter().rev().eq(["f", "e", "d", "c", "b", "a"].iter()));
+
+    assert_eq!(list.remove(4), Some(String::from("e")));
+    assert_eq!(list.remove(1), Some(String::from("b")));
+    assert_eq!(list.remove(3), Some(String::from("f")));
+    assert_eq!(list.remove(0), Some(String::from("a")));
+    assert_eq!(list.remove(2), None);
+
+    assert_eq!(list, ["c", "d"].map(String::from));
+    assert!(list.iter().rev().eq(["d", "c"].iter()));
+    assert_eq!((list.front(), list.back()), (Some(&String::from("c")), Some(&String::from("d"))));
+}
+
+
+#[test]
+#[should_panic]
+fn insert_out_of_bounds() {
+    let mut list = dl_list![1, 2, 3];
+    list.insert(4, 4);
+}
+
+
+#[test]
+fn retain() {
+    let mut list = DoublyLinkedList::from(["a", "bb", "c", "dd", "ee"].map(String::from));
+    list.retain(|x| x.len() == 2);
+
+    assert_eq!(list, ["bb", "dd", "ee"].map(String::from));
+    assert!(list.iter().rev().eq(["ee", "dd", "bb"].iter()));
+
+    list.retain(|x| x != "ee");
+    list.push_back(String::from("f"));
+    assert!(list.iter().rev().eq(["f", "dd", "bb"].iter()));
+
+    list.retain(|_| false);
+    assert_eq!((list.len(), list.front(), list.back()), (0, None, None));
+}
+
+
+#[test]
+fn split_off() {
+    let mut list = DoublyLinkedList::from(["0", "1", "a", "b", "c"].map(String::from));
+    let tail = list.split_off(2);
+
+    assert_eq!(list, ["0", "1"].map(String::from));
+    assert_eq!(tail, ["a", "b", "c"].map(String::from));
+    assert!(list.iter().rev().eq(["1", "0"].iter()));
+    assert!(tail.iter().rev().eq(["c", "b", "a"].iter()));
+
+    let empty = list.split_off(2);
+    assert!(empty.is_empty() && empty.front().is_none() && empty.back().is_none());
+    assert_eq!(list.len(), 2);
+
+    let all = list.split_off(0);
+    assert!(list.is_empty() && list.front().is_none() && list.back().is_none());
+    assert_eq!(all, ["0", "1"].map(String::from));
+}
+
+
+#[test]
+#[should_panic]
+fn split_off_out_of_bounds() {
+    let mut list = dl_list![1, 2, 3];
+    list.split_off(4);
+}
+
+
+#[test]
+fn get_empty() {
+    let mut list = DoublyLinkedList::<i32>::new();
+
+    assert_eq!(list.get(0), None);
+    assert_eq!(list.get(1), None);
+    assert_eq!(list.get_mut(1), None);
+
+    list.push_back(1);
+    list.push_back(2);
+    list.push_back(3);
+
+    assert_eq!(list.get(3), None);
+    assert_eq!(list.get_mut(2), Some(&mut 3));
+}
+
+
+#[test]
+#[should_panic(expected = "Index '1' out of bounds")]
+fn index_empty() {
+    let list = DoublyLinkedList::<i32>::new();
+    let _ = list[1];
+}
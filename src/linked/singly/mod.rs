@@ -16,9 +16,16 @@ mod tests;
 pub mod node;
 
 use node::Node;
-use core::ptr::{NonNull, read as ptr_read};
-use core::iter::{Iterator, IntoIterator, ExactSizeIterator};
-use core::cmp::{Eq, PartialEq};
+use super::doubly::DoublyLinkedList;
+use crate::dynamic::list::List;
+use core::ptr::NonNull;
+use core::iter::{Iterator, IntoIterator, FromIterator, Extend, ExactSizeIterator, FusedIterator};
+use core::marker::PhantomData;
+use core::mem::take;
+use std::boxed::Box;
+use core::ops::{Add, AddAssign};
+use core::cmp::{Eq, PartialEq, Ord, PartialOrd, Ordering};
+use core::hash::{Hash, Hasher};
 use core::option::Option;
 use core::fmt;
 
@@ -28,19 +35,76 @@ pub struct SinglyLinkedList<T> {
     /// [`Node`] at the `front` of the [`SinglyLinkedList`].
     head: Option<NonNull<Node<T>>>,
 
+    /// [`Node`] at the `back` of the [`SinglyLinkedList`], kept so that appending is `O(1)`.
+    tail: Option<NonNull<Node<T>>>,
+
     /// Length of the [`SinglyLinkedList`], represents how many [`Node`]s are contained within.
     len: usize,
 }
 
 
 /// Version of a [`SinglyLinkedList`] that implements the [`Iterator`] trait, a [`SinglyLinkedList`]'s [`IntoIter`].
-pub struct Iter<T> {
+pub struct IntoIter<T> {
     /// [`SinglyLinkedList`] used for iterating over.
     list: SinglyLinkedList<T>,
 }
 
 
-impl<T> Iterator for Iter<T> {
+/// Borrowing [`Iterator`] over the values of a [`SinglyLinkedList`], created by [`SinglyLinkedList::iter()`].
+pub struct Iter<'a, T> {
+    /// Next [`Node`] to be yielded.
+    current: Option<NonNull<Node<T>>>,
+
+    /// Number of [`Node`]s that have yet to be yielded.
+    len: usize,
+
+    /// Binds the [`Iter`] to the lifetime of the borrowed [`SinglyLinkedList`].
+    marker: PhantomData<&'a Node<T>>,
+}
+
+
+/// Mutably borrowing [`Iterator`] over the values of a [`SinglyLinkedList`], created by [`SinglyLinkedList::iter_mut()`].
+pub struct IterMut<'a, T> {
+    /// Next [`Node`] to be yielded.
+    current: Option<NonNull<Node<T>>>,
+
+    /// Number of [`Node`]s that have yet to be yielded.
+    len: usize,
+
+    /// Binds the [`IterMut`] to the lifetime of the mutably borrowed [`SinglyLinkedList`].
+    marker: PhantomData<&'a mut Node<T>>,
+}
+
+
+/// Cursor over a [`SinglyLinkedList`], created by [`SinglyLinkedList::cursor_front()`].
+/// A cursor points at a [`Node`] or at the "ghost" position past the `back` of the list, which wraps around to the `front`.
+pub struct Cursor<'a, T> {
+    /// [`Node`] the cursor is pointing at, `None` when pointing at the ghost position.
+    current: Option<NonNull<Node<T>>>,
+
+    /// Index of `current`, equal to the list's `len` when pointing at the ghost position.
+    index: usize,
+
+    /// [`SinglyLinkedList`] the cursor is borrowing.
+    list: &'a SinglyLinkedList<T>,
+}
+
+
+/// Cursor over a [`SinglyLinkedList`] that can edit the list at its position, created by [`SinglyLinkedList::cursor_front_mut()`].
+/// Edits are made after the current [`Node`], and every edit is `O(1)`, including [`CursorMut::split_after()`] on the ghost position.
+pub struct CursorMut<'a, T> {
+    /// [`Node`] the cursor is pointing at, `None` when pointing at the ghost position.
+    current: Option<NonNull<Node<T>>>,
+
+    /// Index of `current`, equal to the list's `len` when pointing at the ghost position.
+    index: usize,
+
+    /// [`SinglyLinkedList`] the cursor is mutably borrowing.
+    list: &'a mut SinglyLinkedList<T>,
+}
+
+
+impl<T> Iterator for IntoIter<T> {
     type Item = T;
     
     #[inline]
@@ -55,7 +119,343 @@ impl<T> Iterator for Iter<T> {
 }
 
 
-impl<T> ExactSizeIterator for Iter<T> {  }
+impl<T> ExactSizeIterator for IntoIter<T> {  }
+
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.current.map(|ptr| {
+            let node = unsafe { &*ptr.as_ptr() };
+
+            self.current = node.next;
+            self.len -= 1;
+
+            &node.value
+        });
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.len, Some(self.len));
+    }
+}
+
+
+impl<T> ExactSizeIterator for Iter<'_, T> {  }
+impl<T> FusedIterator for Iter<'_, T> {  }
+
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.current.map(|ptr| {
+            let node = unsafe { &mut *ptr.as_ptr() };
+
+            self.current = node.next;
+            self.len -= 1;
+
+            &mut node.value
+        });
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.len, Some(self.len));
+    }
+}
+
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {  }
+impl<T> FusedIterator for IterMut<'_, T> {  }
+
+
+impl<'a, T> Cursor<'a, T> {
+    /// Returns the index of the [`Node`] the cursor is pointing at, or `None` when pointing at the ghost position.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = sl_list![1, 2];
+    /// let mut cursor = list.cursor_front();
+    /// 
+    /// cursor.move_next();
+    /// assert_eq!(cursor.index(), Some(1));
+    /// 
+    /// cursor.move_next();
+    /// assert_eq!(cursor.index(), None);
+    /// ```
+    #[inline]
+    pub fn index(&self) -> Option<usize> {
+        return self.current.map(|_| self.index);
+    }
+
+    /// Moves the cursor to the next [`Node`], moving onto the ghost position past the `back` of the list.
+    /// Moving from the ghost position moves the cursor to the `front` of the list.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = sl_list![1, 2];
+    /// let mut cursor = list.cursor_front();
+    /// 
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), Some(&2));
+    /// 
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), None);
+    /// 
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(ptr) => {
+                self.current = unsafe { ptr.as_ref().next };
+                self.index += 1;
+            },
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            },
+        }
+    }
+
+    /// Returns a reference to the value the cursor is pointing at, or `None` when pointing at the ghost position.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = sl_list![1, 2];
+    /// let cursor = list.cursor_front();
+    /// 
+    /// assert_eq!(cursor.current(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn current(&self) -> Option<&'a T> {
+        return self.current.map(|ptr| unsafe { &(*ptr.as_ptr()).value });
+    }
+
+    /// Returns a reference to the value after the cursor, which is the `front` of the list when pointing at the ghost position.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = sl_list![1, 2];
+    /// let cursor = list.cursor_front();
+    /// 
+    /// assert_eq!(cursor.peek_next(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn peek_next(&self) -> Option<&'a T> {
+        let next = match self.current {
+            Some(ptr) => unsafe { ptr.as_ref().next },
+            None => self.list.head,
+        };
+
+        return next.map(|ptr| unsafe { &(*ptr.as_ptr()).value });
+    }
+}
+
+
+impl<'a, T> CursorMut<'a, T> {
+    /// Returns the index of the [`Node`] the cursor is pointing at, or `None` when pointing at the ghost position.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 2];
+    /// let mut cursor = list.cursor_front_mut();
+    /// 
+    /// cursor.move_next();
+    /// assert_eq!(cursor.index(), Some(1));
+    /// ```
+    #[inline]
+    pub fn index(&self) -> Option<usize> {
+        return self.current.map(|_| self.index);
+    }
+
+    /// Moves the cursor to the next [`Node`], moving onto the ghost position past the `back` of the list.
+    /// Moving from the ghost position moves the cursor to the `front` of the list.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 2];
+    /// let mut cursor = list.cursor_front_mut();
+    /// 
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), Some(&mut 2));
+    /// ```
+    #[inline]
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(ptr) => {
+                self.current = unsafe { ptr.as_ref().next };
+                self.index += 1;
+            },
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            },
+        }
+    }
+
+    /// Returns a mutable reference to the value the cursor is pointing at, or `None` when pointing at the ghost position.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 2];
+    /// let mut cursor = list.cursor_front_mut();
+    /// 
+    /// *cursor.current().unwrap() = 5;
+    /// assert_eq!(list, sl_list![5, 2]);
+    /// ```
+    #[inline]
+    pub fn current(&mut self) -> Option<&mut T> {
+        return self.current.map(|ptr| unsafe { &mut (*ptr.as_ptr()).value });
+    }
+
+    /// Returns a mutable reference to the value after the cursor, which is the `front` of the list when pointing at the ghost position.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 2];
+    /// let mut cursor = list.cursor_front_mut();
+    /// 
+    /// assert_eq!(cursor.peek_next(), Some(&mut 2));
+    /// ```
+    #[inline]
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = match self.current {
+            Some(ptr) => unsafe { ptr.as_ref().next },
+            None => self.list.head,
+        };
+
+        return next.map(|ptr| unsafe { &mut (*ptr.as_ptr()).value });
+    }
+
+    /// Returns a read-only [`Cursor`] pointing at the same position, borrowing the [`CursorMut`] for its lifetime.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 2];
+    /// let cursor = list.cursor_front_mut();
+    /// 
+    /// assert_eq!(cursor.as_cursor().current(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn as_cursor(&self) -> Cursor<'_, T> {
+        return Cursor {
+            current: self.current,
+            index: self.index,
+            list: self.list,
+        };
+    }
+
+    /// Inserts a new [`Node`] with the coresponding `value` after the cursor, without moving it.
+    /// When pointing at the ghost position the value is inserted at the `front` of the list, time complexity is `O(1)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 3];
+    /// let mut cursor = list.cursor_front_mut();
+    /// 
+    /// cursor.insert_after(2);
+    /// assert_eq!(list, sl_list![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn insert_after(&mut self, value: T) {
+        let mut current = match self.current {
+            Some(ptr) => ptr,
+            None => {
+                self.list.push_front(value);
+                self.index = self.list.len;
+                return;
+            },
+        };
+
+        let current_node = unsafe { current.as_mut() };
+
+        let mut node = Node::new(value);
+        node.next = current_node.next;
+
+        let ptr = node.into_non_null();
+        current_node.next = Some(ptr);
+
+        if self.list.tail == Some(current) { self.list.tail = Some(ptr); }
+        self.list.len += 1;
+    }
+
+    /// Removes the [`Node`] after the cursor, returning its `value` field or `None` if there is no such [`Node`].
+    /// When pointing at the ghost position the `front` of the list is removed, time complexity is `O(1)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 2, 3];
+    /// let mut cursor = list.cursor_front_mut();
+    /// 
+    /// assert_eq!(cursor.remove_after(), Some(2));
+    /// assert_eq!(list, sl_list![1, 3]);
+    /// ```
+    #[inline]
+    pub fn remove_after(&mut self) -> Option<T> {
+        let mut current = match self.current {
+            Some(ptr) => ptr,
+            None => {
+                let value = self.list.pop_front();
+                self.index = self.list.len;
+
+                return value;
+            },
+        };
+
+        let current_node = unsafe { current.as_mut() };
+
+        // SAFETY: Every `Node` is allocated through `Node::into_non_null()`, and it is unlinked before being freed.
+        let node = unsafe { Box::from_raw(current_node.next?.as_ptr()) };
+        current_node.next = node.next;
+
+        if node.next.is_none() { self.list.tail = Some(current); }
+        self.list.len -= 1;
+
+        return Some(node.value);
+    }
+
+    /// Splits the list after the cursor, returning a new list holding every [`Node`] after it.
+    /// When pointing at the ghost position the entire list is returned, time complexity is `O(1)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 2, 3];
+    /// let mut cursor = list.cursor_front_mut();
+    /// 
+    /// assert_eq!(cursor.split_after(), sl_list![2, 3]);
+    /// assert_eq!(list, sl_list![1]);
+    /// ```
+    #[inline]
+    pub fn split_after(&mut self) -> SinglyLinkedList<T> {
+        let mut current = match self.current {
+            Some(ptr) => ptr,
+            None => {
+                self.index = 0;
+                return take(self.list);
+            },
+        };
+
+        let head = unsafe { current.as_mut().next.take() };
+
+        let split = SinglyLinkedList {
+            head,
+            tail: if head.is_some() { self.list.tail } else { None },
+            len: self.list.len - self.index - 1,
+        };
+
+        self.list.tail = Some(current);
+        self.list.len = self.index + 1;
+
+        return split;
+    }
+}
 
 
 impl<T> SinglyLinkedList<T> {
@@ -64,6 +464,7 @@ impl<T> SinglyLinkedList<T> {
     pub const fn new() -> Self {
         return Self {
             head: None,
+            tail: None,
             len: 0,
         };
     }
@@ -85,7 +486,24 @@ impl<T> SinglyLinkedList<T> {
         return self.len;
     }
 
+    /// Returns a `bool` that determines if the list is empty.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = SinglyLinkedList::<i32>::new();
+    /// 
+    /// assert_eq!(list.is_empty(), true);
+    /// 
+    /// list.push_back(1);
+    /// assert_eq!(list.is_empty(), false);
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
     /// Clears the [`SinglyLinkedList`] settings its fields back to their default values.
+    /// Every [`Node`] previously within the list is dropped and deallocated.
     /// 
     /// ## Example
     /// ```rust
@@ -99,6 +517,39 @@ impl<T> SinglyLinkedList<T> {
         *self = Self::new();
     }
 
+    /// Shortens the list to `len`, freeing every [`Node`] past it in a single traversal.
+    /// Has no effect if `len` is greater than or equal to the list's `len`, time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 2, 3, 4, 5];
+    /// list.truncate(2);
+    /// 
+    /// assert_eq!(list, sl_list![1, 2]);
+    /// ```
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len { return; }
+
+        let mut current = if len == 0 {
+            self.tail = None;
+            self.head.take()
+        } else {
+            let mut last = self.node_at(len - 1);
+            self.tail = Some(last);
+
+            unsafe { last.as_mut().next.take() }
+        };
+
+        self.len = len;
+
+        while let Some(ptr) = current {
+            // SAFETY: Every `Node` is allocated through `Node::into_non_null()`, and the chain was unlinked from the list above.
+            let node = unsafe { Box::from_raw(ptr.as_ptr()) };
+            current = node.next;
+        }
+    }
+
     /// Returns a reference to the [`Node`] at the `front` of the [`SinglyLinkedList`], also known as the `head`.
     /// Time complexity is `O(1)`.
     /// 
@@ -117,7 +568,7 @@ impl<T> SinglyLinkedList<T> {
     }
 
     /// Returns a reference to the [`Node`] at the `back` of the [`SinglyLinkedList`], also known as the `tail`.
-    /// Time complexity is `O(n)`.
+    /// Time complexity is `O(1)`.
     /// 
     /// ## Example
     /// ```rust
@@ -127,21 +578,10 @@ impl<T> SinglyLinkedList<T> {
     /// ```
     #[inline]
     pub fn back(&self) -> Option<&T> {
-        if let Some(ptr) = self.head {
-            let mut current = Some(ptr);
-
-            while let Some(ptr) = current {
-                let node = unsafe { ptr.as_ref() };
-
-                if node.next.is_none() {
-                    return Some(&node.value);
-                }
-
-                current = node.next;
-            }
-        }
-
-        return None;
+        return match self.tail {
+            Some(ptr) => unsafe { Some(&ptr.as_ref().value) },
+            None => None,
+        };
     }
 
     /// Returns a mutable reference to the [`Node`] at the `front` of the [`SinglyLinkedList`], also known as the `head`.
@@ -162,7 +602,7 @@ impl<T> SinglyLinkedList<T> {
     }
 
     /// Returns a mutable reference to the [`Node`] at the `back` of the [`SinglyLinkedList`], also known as the `tail`.
-    /// Time complexity is `O(n)`.
+    /// Time complexity is `O(1)`.
     /// 
     /// ## Example
     /// ```rust
@@ -172,21 +612,10 @@ impl<T> SinglyLinkedList<T> {
     /// ```
     #[inline]
     pub fn back_mut(&mut self) -> Option<&mut T> {
-        if let Some(ptr) = self.head {
-            let mut current = Some(ptr);
-
-            while let Some(mut ptr) = current {
-                let node = unsafe { ptr.as_mut() };
-
-                if node.next.is_none() {
-                    return Some(&mut node.value);
-                }
-
-                current = node.next;
-            }
-        }
-
-        return None;
+        return match self.tail {
+            Some(mut ptr) => unsafe { Some(&mut ptr.as_mut().value) },
+            None => None,
+        };
     }
 
     /// Pushes a new [`Node`] with the coresponding `value` to the `front` of the list, making the list's `head` the new [`Node`].
@@ -208,13 +637,14 @@ impl<T> SinglyLinkedList<T> {
         new_node.next = self.head;
 
         let ptr = new_node.into_non_null();
+        if self.tail.is_none() { self.tail = Some(ptr); }
 
         self.len += 1;
         self.head = Some(ptr);
     }
 
     /// Pushes a new [`Node`] with the coresponding `value` to the `back` of the list, making the list's last [`Node`] the new [`Node`].
-    /// Time complexity is `O(n)`.
+    /// Time complexity is `O(1)`.
     /// 
     /// ## Example
     /// ```rust
@@ -228,28 +658,15 @@ impl<T> SinglyLinkedList<T> {
     /// ```
     #[inline]
     pub fn push_back(&mut self, value: T) {
-        let ptr = Node::new(value).into_non_null();
+        let ptr = Some(Node::new(value).into_non_null());
 
-        match self.head {
-            Some(x) => unsafe {
-                let mut current = Some(x);
-
-                while let Some(mut x) = current {
-                    let m = x.as_mut();
-
-                    if m.next.is_none() {
-                        m.next = Some(ptr);
-                        break;
-                    }
-
-                    current = m.next;
-                }
-            },
-
-            None => self.head = Some(ptr),
+        match self.tail {
+            Some(mut tail) => unsafe { tail.as_mut().next = ptr; },
+            None => { self.head = ptr; },
         }
 
-        self.len += 1
+        self.len += 1;
+        self.tail = ptr;
     }
 
     /// Removes the [`Node`] at the `front` of the list and returns its `value` field.
@@ -266,10 +683,12 @@ impl<T> SinglyLinkedList<T> {
     #[inline]
     pub fn pop_front(&mut self) -> Option<T> {
         return match self.head {
-            Some(mut ptr) => unsafe {
-                let node = ptr_read(&mut (*ptr.as_mut()));
+            Some(ptr) => unsafe {
+                let node = Box::from_raw(ptr.as_ptr());
                 self.head = node.next;
                 self.len -= 1;
+
+                if self.head.is_none() { self.tail = None; }
                 Some(node.value)
             },
 
@@ -292,20 +711,366 @@ impl<T> SinglyLinkedList<T> {
         let _ = self.pop_front();
     }
 
-    /// Returns a reference to the [`Node`] at the given `index` within the list.
-    /// Time complexity is `O(n)`.
+    /// Removes the [`Node`] at the `back` of the list and returns its `value` field.
+    /// Time complexity is `O(n)`, as the [`Node`] preceding the `tail` has to be found from the `head`.
     /// 
     /// ## Example
     /// ```rust
-    /// let list = sl_list![1, 2, 3];
+    /// let mut list = sl_list![3, 0, 0, 5];
+    /// let value = list.pop_back();
     /// 
-    /// assert_eq!(list.get(0), Some(&1));
-    /// assert_eq!(list.get(1), Some(&2));
-    /// assert_eq!(list.get(2), Some(&3));
+    /// assert_eq!(value, Some(5));
+    /// assert_eq!(list, sl_list![3, 0, 0]);
     /// ```
     #[inline]
-    pub fn get(&self, index: usize) -> Option<&T> {
-        if index == 0 { return self.front(); }
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.tail?;
+        if self.head == Some(tail) { return self.pop_front(); }
+
+        let mut current = self.head;
+
+        while let Some(mut ptr) = current {
+            let node = unsafe { ptr.as_mut() };
+
+            if node.next == Some(tail) {
+                node.next = None;
+                self.tail = Some(ptr);
+                self.len -= 1;
+
+                // SAFETY: Every `Node` is allocated through `Node::into_non_null()`, and the old `tail` is now unreachable from the list.
+                let node = unsafe { Box::from_raw(tail.as_ptr()) };
+                return Some(node.value);
+            }
+
+            current = node.next;
+        }
+
+        return None;
+    }
+
+    /// Removes the [`Node`] at the `back` of the list.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 2, 3];
+    /// list.remove_back();
+    /// 
+    /// assert_eq!(list, sl_list![1, 2]);
+    /// ```
+    #[inline]
+    pub fn remove_back(&mut self) {
+        let _ = self.pop_back();
+    }
+
+    /// Inserts a new [`Node`] with the coresponding `value` at `index`, relinking the [`Node`] before it.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Panics
+    /// Panics if `index` is greater than the list's `len`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 3];
+    /// list.insert(1, 2);
+    /// 
+    /// assert_eq!(list, sl_list![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, index: usize, value: T) {
+        if index > self.len { panic!("Index '{}' out of bounds.", index); }
+
+        if index == 0 { return self.push_front(value); }
+        if index == self.len { return self.push_back(value); }
+
+        let mut prev = self.node_at(index - 1);
+        let prev = unsafe { prev.as_mut() };
+
+        let mut node = Node::new(value);
+        node.next = prev.next;
+
+        prev.next = Some(node.into_non_null());
+        self.len += 1;
+    }
+
+    /// Removes the [`Node`] at `index`, relinking the [`Node`] before it and returning its `value` field.
+    /// Returns `None` if `index` is out of bounds, time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 2, 3];
+    /// 
+    /// assert_eq!(list.remove(1), Some(2));
+    /// assert_eq!(list.remove(2), None);
+    /// assert_eq!(list, sl_list![1, 3]);
+    /// ```
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len { return None; }
+        if index == 0 { return self.pop_front(); }
+
+        let mut prev = self.node_at(index - 1);
+        let prev_node = unsafe { prev.as_mut() };
+
+        // SAFETY: `index < len`, so the `Node` after `prev` exists, and it is unlinked before being freed.
+        let node = unsafe { Box::from_raw(prev_node.next?.as_ptr()) };
+        prev_node.next = node.next;
+
+        if node.next.is_none() { self.tail = Some(prev); }
+        self.len -= 1;
+
+        return Some(node.value);
+    }
+
+    /// Retains only the values for which `predicate` returns `true`, unlinking and freeing every other [`Node`] in a single traversal.
+    /// The relative order of the retained values is preserved, time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 2, 3, 4, 5];
+    /// list.retain(|x| x % 2 == 1);
+    /// 
+    /// assert_eq!(list, sl_list![1, 3, 5]);
+    /// ```
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut predicate: F) {
+        let mut link = &mut self.head;
+        let mut last = None;
+
+        while let Some(ptr) = *link {
+            if predicate(unsafe { &ptr.as_ref().value }) {
+                last = Some(ptr);
+                link = unsafe { &mut (*ptr.as_ptr()).next };
+                continue;
+            }
+
+            // SAFETY: Every `Node` is allocated through `Node::into_non_null()`, and it is unlinked before its value is dropped.
+            let node = unsafe { Box::from_raw(ptr.as_ptr()) };
+
+            *link = node.next;
+            self.len -= 1;
+
+            if node.next.is_none() { self.tail = last; }
+        }
+    }
+
+    /// Splits the list at `at`, returning a new list holding the [`Node`]s from `at` onwards.
+    /// The chain is cut in place and no [`Node`]s are reallocated, time complexity is `O(n)`.
+    /// 
+    /// ## Panics
+    /// Panics if `at` is greater than the list's `len`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 2, 3, 4];
+    /// let tail = list.split_off(1);
+    /// 
+    /// assert_eq!(list, sl_list![1]);
+    /// assert_eq!(tail, sl_list![2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        if at > self.len { panic!("Index '{}' out of bounds.", at); }
+        if at == 0 { return take(self); }
+
+        let mut last = self.node_at(at - 1);
+        let head = unsafe { last.as_mut().next.take() };
+
+        let split = Self {
+            head,
+            tail: if head.is_some() { self.tail } else { None },
+            len: self.len - at,
+        };
+
+        self.tail = Some(last);
+        self.len = at;
+
+        return split;
+    }
+
+    /// Moves every [`Node`] of `other` onto the `back` of the list by splicing the chains together, leaving `other` empty.
+    /// Time complexity is `O(1)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 2];
+    /// let mut other = sl_list![3, 4];
+    /// 
+    /// list.append(&mut other);
+    /// 
+    /// assert_eq!(list, sl_list![1, 2, 3, 4]);
+    /// assert!(other.is_empty());
+    /// ```
+    #[inline]
+    pub fn append(&mut self, other: &mut Self) {
+        let head = match other.head.take() {
+            Some(head) => head,
+            None => return,
+        };
+
+        match self.tail {
+            Some(mut tail) => unsafe { tail.as_mut().next = Some(head); },
+            None => { self.head = Some(head); },
+        }
+
+        self.tail = other.tail.take();
+        self.len += other.len;
+        other.len = 0;
+    }
+
+    /// Returns a pointer to the [`Node`] at `index`, which must be less than the list's `len`.
+    #[inline]
+    fn node_at(&self, index: usize) -> NonNull<Node<T>> {
+        debug_assert!(index < self.len);
+
+        let mut current = self.head;
+        for _ in 0..index { current = unsafe { current.and_then(|ptr| ptr.as_ref().next) }; }
+
+        return current.unwrap_or_else(|| panic!("Index '{}' out of bounds.", index));
+    }
+
+    /// Returns an [`Iterator`] over references to the values within the list, from `front` to `back`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = sl_list![1, 2, 3];
+    /// let mut iter = list.iter();
+    /// 
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        return Iter {
+            current: self.head,
+            len: self.len,
+            marker: PhantomData,
+        };
+    }
+
+    /// Returns an [`Iterator`] over mutable references to the values within the list, from `front` to `back`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 2, 3];
+    /// 
+    /// for value in list.iter_mut() {
+    ///     *value *= 2;
+    /// }
+    /// 
+    /// assert_eq!(list, sl_list![2, 4, 6]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        return IterMut {
+            current: self.head,
+            len: self.len,
+            marker: PhantomData,
+        };
+    }
+
+    /// Returns a [`Cursor`] pointing at the `front` of the list, or at the ghost position if the list is empty.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = sl_list![1, 2, 3];
+    /// let mut cursor = list.cursor_front();
+    /// 
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        return Cursor {
+            current: self.head,
+            index: 0,
+            list: self,
+        };
+    }
+
+    /// Returns a [`CursorMut`] pointing at the `front` of the list, or at the ghost position if the list is empty.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 2, 3];
+    /// let mut cursor = list.cursor_front_mut();
+    /// 
+    /// cursor.move_next();
+    /// cursor.remove_after();
+    /// 
+    /// assert_eq!(list, sl_list![1, 2]);
+    /// ```
+    #[inline]
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        return CursorMut {
+            current: self.head,
+            index: 0,
+            list: self,
+        };
+    }
+
+    /// Consumes the list, returning an [`Iterator`] that yields its values from `back` to `front`.
+    /// The [`Node`] chain is reversed once upfront, making this `O(n)` in total rather than `O(n²)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = sl_list![1, 2, 3];
+    /// let mut iter = list.into_iter_rev();
+    /// 
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn into_iter_rev(mut self) -> IntoIter<T> {
+        self.reverse();
+        return IntoIter { list: self };
+    }
+
+    /// Reverses the order of the [`Node`]s within the list in place, by flipping each `next` pointer.
+    /// Time complexity is `O(n)`, no [`Node`]s are allocated.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 2, 3];
+    /// list.reverse();
+    /// 
+    /// assert_eq!(list, sl_list![3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn reverse(&mut self) {
+        let mut prev = None;
+        let mut current = self.head;
+
+        while let Some(mut ptr) = current {
+            let node = unsafe { ptr.as_mut() };
+
+            current = node.next;
+            node.next = prev;
+            prev = Some(ptr);
+        }
+
+        self.tail = self.head;
+        self.head = prev;
+    }
+
+    /// Returns a reference to the [`Node`] at the given `index` within the list.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = sl_list![1, 2, 3];
+    /// 
+    /// assert_eq!(list.get(0), Some(&1));
+    /// assert_eq!(list.get(1), Some(&2));
+    /// assert_eq!(list.get(2), Some(&3));
+    /// ```
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index == 0 { return self.front(); }
         if index >= self.len { return None; }
 
         let mut current = self.head;
@@ -357,16 +1122,411 @@ impl<T> SinglyLinkedList<T> {
 
         return None;
     }
+
+    /// Consumes the list, splitting it into the values for which `predicate` returns `true` and those for which it returns `false`.
+    /// The relative order of the values is preserved within both lists, [`Node`]s are relinked rather than reallocated.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let (evens, odds) = sl_list![1, 2, 3, 4].partition(|x| x % 2 == 0);
+    /// 
+    /// assert_eq!(evens, sl_list![2, 4]);
+    /// assert_eq!(odds, sl_list![1, 3]);
+    /// ```
+    #[inline]
+    pub fn partition<P: FnMut(&T) -> bool>(mut self, mut predicate: P) -> (Self, Self) {
+        let mut matching = Self::new();
+        let mut rest = Self::new();
+
+        let mut matching_tail: Option<NonNull<Node<T>>> = None;
+        let mut rest_tail: Option<NonNull<Node<T>>> = None;
+
+        let mut current = self.head.take();
+        self.tail = None;
+        self.len = 0;
+
+        while let Some(mut ptr) = current {
+            let node = unsafe { ptr.as_mut() };
+            current = node.next.take();
+
+            let (list, tail) = if predicate(&node.value) {
+                (&mut matching, &mut matching_tail)
+            } else {
+                (&mut rest, &mut rest_tail)
+            };
+
+            match *tail {
+                Some(mut tail) => unsafe { tail.as_mut().next = Some(ptr); },
+                None => { list.head = Some(ptr); },
+            }
+
+            *tail = Some(ptr);
+            list.len += 1;
+        }
+
+        matching.tail = matching_tail;
+        rest.tail = rest_tail;
+
+        return (matching, rest);
+    }
+
+    /// Returns the index of the first value within the list for which `predicate` returns `true`.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = sl_list![1, 2, 3, 4];
+    /// 
+    /// assert_eq!(list.position(|x| x % 2 == 0), Some(1));
+    /// assert_eq!(list.position(|x| *x > 4), None);
+    /// ```
+    #[inline]
+    pub fn position<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<usize> {
+        return self.iter().position(predicate);
+    }
+
+    /// Returns a reference to the first value within the list for which `predicate` returns `true`.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = sl_list![1, 2, 3, 4];
+    /// 
+    /// assert_eq!(list.find(|x| x % 2 == 0), Some(&2));
+    /// assert_eq!(list.find(|x| *x > 4), None);
+    /// ```
+    #[inline]
+    pub fn find<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<&T> {
+        return self.iter().find(|x| predicate(x));
+    }
+
+    /// Returns a reference to the last value within the list for which `predicate` returns `true`.
+    /// Time complexity is `O(n)`, as every value must be visited.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = sl_list![1, 2, 3, 4];
+    /// 
+    /// assert_eq!(list.rfind(|x| x % 2 == 0), Some(&4));
+    /// assert_eq!(list.rfind(|x| *x > 4), None);
+    /// ```
+    #[inline]
+    pub fn rfind<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<&T> {
+        return self.iter().filter(|x| predicate(x)).last();
+    }
+}
+
+
+impl<T: PartialEq> SinglyLinkedList<T> {
+    /// Returns `true` if the list contains a value equal to `value`.
+    /// Time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let list = sl_list![1, 2, 3];
+    /// 
+    /// assert!(list.contains(&2));
+    /// assert!(!list.contains(&4));
+    /// ```
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        return self.iter().any(|x| x == value);
+    }
+
+    /// Removes consecutive repeated values from the list, unlinking and freeing the duplicate [`Node`]s in a single traversal.
+    /// If the list is sorted, this removes every duplicate, time complexity is `O(n)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 1, 2, 3, 3, 3, 1];
+    /// list.dedup();
+    /// 
+    /// assert_eq!(list, sl_list![1, 2, 3, 1]);
+    /// ```
+    #[inline]
+    pub fn dedup(&mut self) {
+        let mut current = match self.head {
+            Some(ptr) => ptr,
+            None => return,
+        };
+
+        while let Some(next) = unsafe { current.as_ref().next } {
+            if unsafe { next.as_ref().value != current.as_ref().value } {
+                current = next;
+                continue;
+            }
+
+            // SAFETY: Every `Node` is allocated through `Node::into_non_null()`, and it is unlinked before its value is dropped.
+            let node = unsafe { Box::from_raw(next.as_ptr()) };
+
+            unsafe { current.as_mut().next = node.next; }
+            self.len -= 1;
+
+            if node.next.is_none() { self.tail = Some(current); }
+        }
+    }
+}
+
+
+impl<T: Ord> SinglyLinkedList<T> {
+    /// Merges the sorted `other` into the sorted list by splicing their [`Node`]s together, leaving `other` empty.
+    /// Equal values from the list are placed before those from `other`, no [`Node`]s are allocated and time complexity is `O(n + m)`.
+    /// 
+    /// ## Example
+    /// ```rust
+    /// let mut list = sl_list![1, 3, 5];
+    /// let mut other = sl_list![2, 3, 4];
+    /// 
+    /// list.merge(&mut other);
+    /// 
+    /// assert_eq!(list, sl_list![1, 2, 3, 3, 4, 5]);
+    /// assert!(other.is_empty());
+    /// ```
+    #[inline]
+    pub fn merge(&mut self, other: &mut Self) {
+        let (mut left, mut right) = (self.head.take(), other.head.take());
+        let (left_tail, right_tail) = (self.tail.take(), other.tail.take());
+
+        let len = self.len + other.len;
+        self.len = 0;
+        other.len = 0;
+
+        let mut tail: Option<NonNull<Node<T>>> = None;
+
+        while let (Some(l), Some(r)) = (left, right) {
+            let next = if unsafe { r.as_ref().value < l.as_ref().value } {
+                right = unsafe { r.as_ref().next };
+                r
+            } else {
+                left = unsafe { l.as_ref().next };
+                l
+            };
+
+            match tail {
+                Some(mut tail) => unsafe { tail.as_mut().next = Some(next); },
+                None => { self.head = Some(next); },
+            }
+
+            tail = Some(next);
+        }
+
+        let (rest, rest_tail) = if left.is_some() { (left, left_tail) } else { (right, right_tail) };
+
+        if rest.is_some() {
+            match tail {
+                Some(mut tail) => unsafe { tail.as_mut().next = rest; },
+                None => { self.head = rest; },
+            }
+
+            tail = rest_tail;
+        }
+
+        self.tail = tail;
+        self.len = len;
+    }
 }
 
 
 impl<T> IntoIterator for SinglyLinkedList<T> {
     type Item = T;
-    type IntoIter = Iter<T>;
+    type IntoIter = IntoIter<T>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        return Iter { list: self };
+        return IntoIter { list: self };
+    }
+}
+
+
+impl<'a, T> IntoIterator for &'a SinglyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter();
+    }
+}
+
+
+impl<'a, T> IntoIterator for &'a mut SinglyLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter_mut();
+    }
+}
+
+
+impl<T> Drop for SinglyLinkedList<T> {
+    #[inline]
+    fn drop(&mut self) {
+        let mut current = self.head;
+
+        while let Some(ptr) = current {
+            // SAFETY: Every `Node` is allocated through `Node::into_non_null()` and is only reachable once through `next`.
+            let node = unsafe { Box::from_raw(ptr.as_ptr()) };
+            current = node.next;
+        }
+    }
+}
+
+
+// SAFETY: The list uniquely owns its `Node`s, so sending it is equivalent to sending every `T` within.
+unsafe impl<T: Send> Send for SinglyLinkedList<T> {  }
+
+// SAFETY: Shared access to the list only ever hands out `&T`, so it is safe to share whenever `T` is.
+unsafe impl<T: Sync> Sync for SinglyLinkedList<T> {  }
+
+// SAFETY: `Iter` behaves like a `&T` for each value within the list.
+unsafe impl<T: Sync> Send for Iter<'_, T> {  }
+unsafe impl<T: Sync> Sync for Iter<'_, T> {  }
+
+// SAFETY: `IterMut` behaves like a `&mut T` for each value within the list.
+unsafe impl<T: Send> Send for IterMut<'_, T> {  }
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {  }
+
+// SAFETY: `Cursor` behaves like a `&T` for each value within the list.
+unsafe impl<T: Sync> Send for Cursor<'_, T> {  }
+unsafe impl<T: Sync> Sync for Cursor<'_, T> {  }
+
+// SAFETY: `CursorMut` behaves like a `&mut T` for each value within the list.
+unsafe impl<T: Send> Send for CursorMut<'_, T> {  }
+unsafe impl<T: Sync> Sync for CursorMut<'_, T> {  }
+
+
+impl<T> Default for SinglyLinkedList<T> {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+
+impl<T: Clone> Clone for SinglyLinkedList<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        return self.iter().cloned().collect();
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        let mut iter = source.iter();
+        let mut link = &mut self.head;
+        let mut tail = None;
+        let mut len = 0;
+
+        while let Some(mut ptr) = *link {
+            let value = match iter.next() {
+                Some(value) => value,
+                None => break,
+            };
+
+            let node = unsafe { ptr.as_mut() };
+            node.value.clone_from(value);
+
+            link = &mut node.next;
+            tail = Some(ptr);
+            len += 1;
+        }
+
+        // The list is made consistent before the surplus values are dropped, in case a drop panics.
+        let mut current = link.take();
+        self.tail = tail;
+        self.len = len;
+
+        while let Some(ptr) = current {
+            // SAFETY: Every `Node` is allocated through `Node::into_non_null()`, and the detached chain is unreachable from the list.
+            let node = unsafe { Box::from_raw(ptr.as_ptr()) };
+            current = node.next;
+        }
+
+        for value in iter {
+            let ptr = Node::new(value.clone()).into_non_null();
+
+            *link = Some(ptr);
+            link = unsafe { &mut (*ptr.as_ptr()).next };
+            self.tail = Some(ptr);
+            self.len += 1;
+        }
+    }
+}
+
+
+impl<T> Add for SinglyLinkedList<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        return self;
+    }
+}
+
+
+impl<T> AddAssign for SinglyLinkedList<T> {
+    #[inline]
+    fn add_assign(&mut self, mut rhs: Self) {
+        self.append(&mut rhs);
+    }
+}
+
+
+impl<T> From<DoublyLinkedList<T>> for SinglyLinkedList<T> {
+    #[inline]
+    fn from(list: DoublyLinkedList<T>) -> Self {
+        return list.into_iter().collect();
+    }
+}
+
+
+impl<T> From<List<T>> for SinglyLinkedList<T> {
+    #[inline]
+    fn from(list: List<T>) -> Self {
+        return list.into_iter().collect();
+    }
+}
+
+
+impl<T, const N: usize> From<[T; N]> for SinglyLinkedList<T> {
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        return IntoIterator::into_iter(array).collect();
+    }
+}
+
+
+impl<T> Extend<T> for SinglyLinkedList<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        *self += iter.into_iter().collect();
+    }
+}
+
+
+impl<T> FromIterator<T> for SinglyLinkedList<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        let mut tail: Option<NonNull<Node<T>>> = None;
+
+        for value in iter {
+            let ptr = Some(Node::new(value).into_non_null());
+
+            match tail {
+                Some(mut tail) => unsafe { tail.as_mut().next = ptr; },
+                None => { list.head = ptr; },
+            }
+
+            tail = ptr;
+            list.len += 1;
+        }
+
+        list.tail = tail;
+        return list;
     }
 }
 
@@ -375,7 +1535,7 @@ impl<T: PartialEq> PartialEq for SinglyLinkedList<T> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         if self.len() != other.len() { return false; }
-        if self.len() == 0 { return true; }
+        if self.is_empty() { return true; }
 
         let mut s = self.head;
         let mut o = other.head;
@@ -398,12 +1558,98 @@ impl<T: PartialEq> PartialEq for SinglyLinkedList<T> {
 impl<T: Eq> Eq for SinglyLinkedList<T> {  }
 
 
+impl<T: PartialEq> PartialEq<[T]> for SinglyLinkedList<T> {
+    #[inline]
+    fn eq(&self, other: &[T]) -> bool {
+        return self.len == other.len() && self.iter().eq(other.iter());
+    }
+}
+
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for SinglyLinkedList<T> {
+    #[inline]
+    fn eq(&self, other: &[T; N]) -> bool {
+        return *self == other[..];
+    }
+}
+
+
+impl<T: PartialEq> PartialEq<Vec<T>> for SinglyLinkedList<T> {
+    #[inline]
+    fn eq(&self, other: &Vec<T>) -> bool {
+        return *self == other[..];
+    }
+}
+
+
+impl<T: PartialEq> PartialEq<SinglyLinkedList<T>> for [T] {
+    #[inline]
+    fn eq(&self, other: &SinglyLinkedList<T>) -> bool {
+        return *other == *self;
+    }
+}
+
+
+impl<T: PartialEq, const N: usize> PartialEq<SinglyLinkedList<T>> for [T; N] {
+    #[inline]
+    fn eq(&self, other: &SinglyLinkedList<T>) -> bool {
+        return *other == self[..];
+    }
+}
+
+
+impl<T: PartialEq> PartialEq<SinglyLinkedList<T>> for Vec<T> {
+    #[inline]
+    fn eq(&self, other: &SinglyLinkedList<T>) -> bool {
+        return *other == self[..];
+    }
+}
+
+
+impl<T: PartialOrd> PartialOrd for SinglyLinkedList<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return self.iter().partial_cmp(other.iter());
+    }
+}
+
+
+impl<T: Ord> Ord for SinglyLinkedList<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        return self.iter().cmp(other.iter());
+    }
+}
+
+
+impl<T: Hash> Hash for SinglyLinkedList<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for value in self.iter() { value.hash(state); }
+    }
+}
+
+
 impl<T: fmt::Debug> fmt::Debug for SinglyLinkedList<T> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        return f.debug_struct("SinglyLinkedList")
-            .field("head", &self.head)
-            .field("len", &self.len)
-            .finish();
+        return f.debug_list().entries(self.iter()).finish();
+    }
+}
+
+
+impl<T: fmt::Display> fmt::Display for SinglyLinkedList<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.len == 0 { return write!(f, "[]"); }
+
+        let mut result = String::from("[");
+
+        for e in self.iter() {
+            result.push_str(format!("{}, ", e).as_str());
+        }
+
+        return write!(f, "{}", result.strip_suffix(", ").unwrap().to_string() + "]");
     }
 }
\ No newline at end of file
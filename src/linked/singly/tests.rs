@@ -1,3 +1,7 @@
+use std::rc::Rc;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
 use super::super::super::sl_list;
 use super::SinglyLinkedList;
 use super::node::Node;
@@ -128,4 +132,669 @@ fn node_into() {
     
     assert_eq!(boxed.value, 5);
     assert_eq!(unsafe { ptr.as_ref().value }, 5);
-}
\ No newline at end of file
+
+    drop(unsafe { Box::from_raw(ptr.as_ptr()) });
+}
+
+#[test]
+fn add() {
+    let list = sl_list![1, 2] + sl_list![3, 4];
+    assert_eq!(list, sl_list![1, 2, 3, 4]);
+
+    let mut list = SinglyLinkedList::new();
+    list += sl_list![1];
+    list += SinglyLinkedList::new();
+    list += sl_list![2, 3];
+
+    assert_eq!(list.len(), 3);
+    assert_eq!(list, sl_list![1, 2, 3]);
+}
+
+
+#[test]
+fn iter_ref() {
+    let list = sl_list![1, 2, 3];
+    let mut iter = list.iter();
+
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    assert_eq!(iter.next(), Some(&2));
+    assert_eq!(iter.next(), Some(&3));
+    assert_eq!(iter.next(), None);
+
+    assert_eq!(list.iter().sum::<i32>(), 6);
+    assert_eq!(list.len(), 3);
+}
+
+
+#[test]
+fn into_iter_ref() {
+    let mut list = sl_list![1, 2, 3];
+
+    for value in &mut list {
+        *value += 1;
+    }
+
+    let mut sum = 0;
+
+    for value in &list {
+        sum += *value;
+    }
+
+    assert_eq!(sum, 9);
+    assert_eq!(list, sl_list![2, 3, 4]);
+}
+
+
+#[test]
+fn into_iter_rev() {
+    let list = sl_list![1, 2, 3, 4];
+    let mut iter = list.into_iter_rev();
+
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some(4));
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), None);
+
+    assert_eq!(SinglyLinkedList::<i32>::new().into_iter_rev().next(), None);
+}
+
+
+#[test]
+fn clone() {
+    let list = sl_list![1, 2, 3];
+    let mut cloned = list.clone();
+
+    *cloned.back_mut().unwrap() = 4;
+
+    assert_eq!(list, sl_list![1, 2, 3]);
+    assert_eq!(cloned, sl_list![1, 2, 4]);
+    assert_eq!(SinglyLinkedList::<i32>::new().clone().len(), 0);
+}
+
+
+#[test]
+fn clone_from() {
+    let mut list = sl_list![1, 2, 3, 4];
+    let head = list.head;
+
+    list.clone_from(&sl_list![5, 6]);
+    assert_eq!(list, sl_list![5, 6]);
+    assert_eq!(list.head, head);
+
+    list.clone_from(&sl_list![7, 8, 9]);
+    assert_eq!(list, sl_list![7, 8, 9]);
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.head, head);
+
+    list.clone_from(&SinglyLinkedList::new());
+    assert_eq!(list.len(), 0);
+    assert_eq!(list.front(), None);
+}
+
+
+#[test]
+fn clone_from_panic() {
+    #[derive(Debug, PartialEq)]
+    struct Bomb(i32);
+
+    impl Clone for Bomb {
+        fn clone(&self) -> Self {
+            if self.0 < 0 { panic!(); }
+            return Bomb(self.0);
+        }
+    }
+
+    impl Drop for Bomb {
+        fn drop(&mut self) {
+            if self.0 == 0 { panic!(); }
+        }
+    }
+
+    let mut list = sl_list![Bomb(1)];
+    let source = sl_list![Bomb(2), Bomb(3), Bomb(-4)];
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| list.clone_from(&source)));
+
+    assert!(result.is_err());
+    assert_eq!((list.len(), list.back()), (2, Some(&Bomb(3))));
+
+    list.push_back(Bomb(5));
+    assert_eq!(list, [Bomb(2), Bomb(3), Bomb(5)]);
+
+    let mut list = sl_list![Bomb(1), Bomb(2), Bomb(0)];
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| list.clone_from(&sl_list![Bomb(6)])));
+
+    assert!(result.is_err());
+    assert_eq!((list.len(), list.back()), (1, Some(&Bomb(6))));
+
+    list.push_back(Bomb(7));
+    assert_eq!(list, [Bomb(6), Bomb(7)]);
+}
+
+
+#[test]
+fn default() {
+    let list = SinglyLinkedList::<i32>::default();
+
+    assert!(list.is_empty());
+    assert_eq!(list.front(), None);
+}
+
+
+fn hash_of<H: Hash>(value: &H) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    return hasher.finish();
+}
+
+
+#[test]
+fn hash() {
+    assert_eq!(hash_of(&sl_list![1, 2, 3]), hash_of(&sl_list![1, 2, 3]));
+    assert_ne!(hash_of(&sl_list![1, 2, 3]), hash_of(&sl_list![3, 2, 1]));
+    assert_ne!(hash_of(&sl_list![vec![1], vec![2, 3]]), hash_of(&sl_list![vec![1, 2], vec![3]]));
+}
+
+
+#[test]
+fn ord() {
+    assert!(sl_list![1, 2, 3] < sl_list![1, 2, 4]);
+    assert!(sl_list![1, 2] < sl_list![1, 2, 3]);
+    assert!(sl_list![2] > sl_list![1, 9, 9]);
+    assert_eq!(sl_list![1, 2].cmp(&sl_list![1, 2]), std::cmp::Ordering::Equal);
+    assert_eq!(sl_list![1.0, f64::NAN].partial_cmp(&sl_list![1.0, 2.0]), None);
+}
+
+
+#[test]
+fn from_iter() {
+    let list = (1 ..= 4).collect::<SinglyLinkedList<_>>();
+
+    assert_eq!(list, sl_list![1, 2, 3, 4]);
+    assert_eq!(list.len(), 4);
+    assert_eq!(list.back(), Some(&4));
+    assert!(std::iter::empty::<i32>().collect::<SinglyLinkedList<_>>().is_empty());
+}
+
+
+#[test]
+fn extend() {
+    let mut list = sl_list![1, 2];
+
+    list.extend(vec![3, 4]);
+    list.extend(5 ..= 6);
+    list.extend(std::iter::empty());
+
+    assert_eq!(list, sl_list![1, 2, 3, 4, 5, 6]);
+    assert_eq!(list.len(), 6);
+}
+
+
+#[test]
+fn send_sync() {
+    fn assert_send_sync<S: Send + Sync>() {  }
+
+    assert_send_sync::<SinglyLinkedList<i32>>();
+    assert_send_sync::<super::Iter<'_, i32>>();
+    assert_send_sync::<super::IterMut<'_, i32>>();
+    assert_send_sync::<super::IntoIter<i32>>();
+
+    let list = sl_list![1, 2, 3];
+    let handle = std::thread::spawn(move || list.into_iter().sum::<i32>());
+
+    assert_eq!(handle.join().unwrap(), 6);
+}
+
+
+#[test]
+fn from_array() {
+    let list = SinglyLinkedList::from([String::from("a"), String::from("b"), String::from("c")]);
+
+    assert_eq!(list, sl_list![String::from("a"), String::from("b"), String::from("c")]);
+    assert_eq!(list.len(), 3);
+    assert!(SinglyLinkedList::<i32>::from([]).is_empty());
+}
+
+
+#[test]
+fn from_lists() {
+    use crate::{list, dl_list};
+
+    assert_eq!(SinglyLinkedList::from(list![1, 2, 3]), sl_list![1, 2, 3]);
+    assert_eq!(SinglyLinkedList::from(dl_list![String::from("a")]), sl_list![String::from("a")]);
+}
+
+
+#[test]
+fn eq_foreign() {
+    let list = sl_list![1, 2, 3];
+
+    assert_eq!(list, [1, 2, 3]);
+    assert_eq!(list, vec![1, 2, 3]);
+    assert_eq!(list, [1, 2, 3][..]);
+    assert_eq!([1, 2, 3], list);
+    assert_eq!(vec![1, 2, 3], list);
+    assert_eq!([1, 2, 3][..], list);
+
+    assert_ne!(list, [1, 2]);
+    assert_ne!(list, vec![1, 2, 4]);
+    assert_ne!([3, 2, 1], list);
+}
+
+
+#[test]
+fn position_find() {
+    let list = sl_list![1, 2, 3, 4];
+
+    assert_eq!(list.position(|x| *x == 3), Some(2));
+    assert_eq!(list.find(|x| x % 2 == 0), Some(&2));
+    assert_eq!(list.rfind(|x| x % 2 == 0), Some(&4));
+    assert_eq!(list.position(|x| *x == 5), None);
+    assert_eq!(list.rfind(|x| *x == 5), None);
+}
+
+
+#[test]
+fn sl_list_macro_repeat() {
+    let list = sl_list![String::from("a"); 3];
+
+    assert_eq!(list, ["a", "a", "a"].map(String::from));
+    assert!(sl_list![0; 0].is_empty());
+    assert_eq!(sl_list![1, 2,], [1, 2]);
+}
+
+
+#[test]
+fn partition() {
+    let list = sl_list![String::from("a"), String::from("bb"), String::from("c"), String::from("dd")];
+    let (short, long) = list.partition(|x| x.len() == 1);
+
+    assert_eq!(short, ["a", "c"].map(String::from));
+    assert_eq!(long, ["bb", "dd"].map(String::from));
+    assert_eq!((short.len(), long.len()), (2, 2));
+
+    let (all, none) = sl_list![1, 2, 3].partition(|_| true);
+
+    assert_eq!(all, [1, 2, 3]);
+    assert!(none.is_empty());
+}
+
+
+#[test]
+fn tail() {
+    let mut list = SinglyLinkedList::new();
+
+    list.push_front(2);
+    list.push_back(3);
+    list.push_front(1);
+    assert_eq!(list.back(), Some(&3));
+
+    while list.pop_front().is_some() {  }
+    assert_eq!(list.back(), None);
+
+    list.push_back(1);
+    *list.back_mut().unwrap() += 1;
+    assert_eq!(list, [2]);
+
+    list += sl_list![3, 4];
+    list.push_back(5);
+    list += SinglyLinkedList::new();
+    list.push_back(6);
+    assert_eq!(list, [2, 3, 4, 5, 6]);
+
+    let mut copy = sl_list![0; 8];
+    copy.clone_from(&list);
+    copy.push_back(7);
+    assert_eq!(copy, [2, 3, 4, 5, 6, 7]);
+
+    let mut copy = sl_list![0; 2];
+    copy.clone_from(&list);
+    copy.push_back(7);
+    assert_eq!(copy, [2, 3, 4, 5, 6, 7]);
+
+    let (mut even, mut odd) = list.partition(|x| x % 2 == 0);
+    even.push_back(8);
+    odd.push_back(9);
+
+    assert_eq!(even, [2, 4, 6, 8]);
+    assert_eq!(odd, [3, 5, 9]);
+}
+
+
+#[test]
+fn pop_back() {
+    let mut list = sl_list![String::from("a"), String::from("b"), String::from("c")];
+
+    assert_eq!(list.pop_back(), Some(String::from("c")));
+    assert_eq!(list.back(), Some(&String::from("b")));
+
+    list.remove_back();
+    list.push_back(String::from("d"));
+    assert_eq!(list, ["a", "d"].map(String::from));
+
+    assert_eq!(list.pop_back(), Some(String::from("d")));
+    assert_eq!(list.pop_back(), Some(String::from("a")));
+    assert_eq!(list.pop_back(), None);
+    assert_eq!((list.len(), list.front(), list.back()), (0, None, None));
+
+    list.push_back(String::from("e"));
+    assert_eq!(list, [String::from("e")]);
+}
+
+
+#[test]
+fn insert_remove() {
+    let mut list = SinglyLinkedList::new();
+
+    list.insert(0, String::from("b"));
+    list.insert(0, String::from("a"));
+    list.insert(2, String::from("d"));
+    list.insert(2, String::from("c"));
+    assert_eq!(list, ["a", "b", "c", "d"].map(String::from));
+
+    assert_eq!(list.remove(3), Some(String::from("d")));
+    assert_eq!(list.back(), Some(&String::from("c")));
+    assert_eq!(list.remove(1), Some(String::from("b")));
+    assert_eq!(list.remove(0), Some(String::from("a")));
+    assert_eq!(list.remove(1), None);
+
+    list.push_back(String::from("e"));
+    assert_eq!(list, ["c", "e"].map(String::from));
+    assert_eq!(list.len(), 2);
+}
+
+
+#[test]
+#[should_panic]
+fn insert_out_of_bounds() {
+    let mut list = sl_list![1, 2, 3];
+    list.insert(4, 4);
+}
+
+
+#[test]
+fn retain() {
+    let mut list: SinglyLinkedList<String> = (0..8).map(|x| x.to_string()).collect();
+    list.retain(|x| x.parse::<i32>().unwrap() % 3 != 0);
+
+    assert_eq!(list, ["1", "2", "4", "5", "7"].map(String::from));
+    assert_eq!(list.len(), 5);
+
+    list.retain(|x| x != "7");
+    list.push_back(String::from("8"));
+    assert_eq!(list, ["1", "2", "4", "5", "8"].map(String::from));
+
+    list.retain(|_| false);
+    assert_eq!((list.len(), list.front(), list.back()), (0, None, None));
+}
+
+
+#[test]
+fn reverse() {
+    let mut list = sl_list![String::from("a"), String::from("b"), String::from("c")];
+
+    list.reverse();
+    assert_eq!(list, ["c", "b", "a"].map(String::from));
+
+    list.push_back(String::from("d"));
+    assert_eq!(list.back(), Some(&String::from("d")));
+    assert_eq!(list.len(), 4);
+
+    let mut list = SinglyLinkedList::<i32>::new();
+    list.reverse();
+    assert!(list.is_empty());
+}
+
+
+#[test]
+fn split_off() {
+    let mut list: SinglyLinkedList<String> = (0..5).map(|x| x.to_string()).collect();
+    let mut tail = list.split_off(2);
+
+    assert_eq!(list, ["0", "1"].map(String::from));
+    assert_eq!(tail, ["2", "3", "4"].map(String::from));
+
+    list.push_back(String::from("a"));
+    tail.push_back(String::from("b"));
+
+    assert_eq!((list.len(), list.back()), (3, Some(&String::from("a"))));
+    assert_eq!((tail.len(), tail.back()), (4, Some(&String::from("b"))));
+
+    let empty = list.split_off(3);
+    assert!(empty.is_empty() && empty.back().is_none());
+
+    let all = list.split_off(0);
+    assert!(list.is_empty() && list.back().is_none());
+    assert_eq!(all, ["0", "1", "a"].map(String::from));
+}
+
+
+#[test]
+#[should_panic]
+fn split_off_out_of_bounds() {
+    let mut list = sl_list![1, 2, 3];
+    list.split_off(4);
+}
+
+
+#[test]
+fn append() {
+    let mut list = sl_list![String::from("a")];
+    let mut other = sl_list![String::from("b"), String::from("c")];
+
+    list.append(&mut other);
+
+    assert_eq!(list, ["a", "b", "c"].map(String::from));
+    assert_eq!((other.len(), other.front(), other.back()), (0, None, None));
+
+    other.push_back(String::from("d"));
+    list.append(&mut SinglyLinkedList::new());
+
+    let mut empty = SinglyLinkedList::new();
+    empty.append(&mut list);
+    empty.append(&mut other);
+
+    assert_eq!(empty, ["a", "b", "c", "d"].map(String::from));
+    assert_eq!(empty.back(), Some(&String::from("d")));
+    assert!(list.is_empty());
+}
+
+
+#[test]
+fn contains() {
+    let list = sl_list![String::from("a"), String::from("b")];
+
+    assert!(list.contains(&String::from("b")));
+    assert!(!list.contains(&String::from("c")));
+    assert_eq!(list.position(|x| x == "b"), Some(1));
+    assert!(!SinglyLinkedList::<i32>::new().contains(&0));
+}
+
+
+#[test]
+fn cursor() {
+    let list = sl_list![1, 2, 3];
+    let mut cursor = list.cursor_front();
+
+    assert_eq!((cursor.index(), cursor.current(), cursor.peek_next()), (Some(0), Some(&1), Some(&2)));
+
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!((cursor.index(), cursor.current(), cursor.peek_next()), (Some(2), Some(&3), None));
+
+    cursor.move_next();
+    assert_eq!((cursor.index(), cursor.current(), cursor.peek_next()), (None, None, Some(&1)));
+
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&1));
+
+    let empty = SinglyLinkedList::<i32>::new();
+    assert_eq!(empty.cursor_front().current(), None);
+}
+
+
+#[test]
+fn cursor_mut() {
+    let mut list = sl_list![String::from("a"), String::from("c")];
+    let mut cursor = list.cursor_front_mut();
+
+    cursor.insert_after(String::from("b"));
+    cursor.move_next();
+    cursor.current().unwrap().push('!');
+    cursor.move_next();
+    cursor.insert_after(String::from("d"));
+
+    assert_eq!(cursor.as_cursor().index(), Some(2));
+    assert_eq!(cursor.peek_next(), Some(&mut String::from("d")));
+
+    cursor.move_next();
+    cursor.move_next();
+    cursor.insert_after(String::from("0"));
+    assert_eq!(cursor.remove_after(), Some(String::from("0")));
+
+    assert_eq!(list, ["a", "b!", "c", "d"].map(String::from));
+    assert_eq!(list.back(), Some(&String::from("d")));
+
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    cursor.move_next();
+
+    assert_eq!(cursor.remove_after(), Some(String::from("d")));
+    assert_eq!(cursor.remove_after(), None);
+    assert_eq!(list.back(), Some(&String::from("c")));
+    assert_eq!(list.len(), 3);
+}
+
+
+#[test]
+fn cursor_split_after() {
+    let mut list = sl_list![1, 2, 3, 4];
+    let mut cursor = list.cursor_front_mut();
+
+    cursor.move_next();
+    assert_eq!(cursor.split_after(), sl_list![3, 4]);
+    assert_eq!(cursor.split_after(), SinglyLinkedList::new());
+
+    cursor.move_next();
+    assert_eq!(cursor.split_after(), sl_list![1, 2]);
+    cursor.insert_after(5);
+
+    assert_eq!((list.len(), list.front(), list.back()), (1, Some(&5), Some(&5)));
+}
+
+
+#[test]
+fn display_debug() {
+    let list = sl_list![String::from("a"), String::from("b")];
+
+    assert_eq!(format!("{}", list), "[a, b]");
+    assert_eq!(format!("{:?}", list), "[\"a\", \"b\"]");
+    assert_eq!(format!("{:#?}", sl_list![1]), "[\n    1,\n]");
+    assert_eq!(format!("{}", SinglyLinkedList::<i32>::new()), "[]");
+}
+
+
+
+#[test]
+fn drop_clear() {
+    let value = Rc::new(0);
+    let mut list = sl_list![value.clone(), value.clone(), value.clone()];
+
+    assert_eq!(Rc::strong_count(&value), 4);
+
+    list.clear();
+    assert!(list.is_empty());
+    assert_eq!(list.back(), None);
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    list.push_back(value.clone());
+    list.push_front(value.clone());
+    assert_eq!(list.pop_back(), Some(value.clone()));
+    assert_eq!(Rc::strong_count(&value), 2);
+
+    let mut other = sl_list![value.clone(), value.clone()];
+    let tail = other.split_off(1);
+    list.append(&mut other);
+
+    assert_eq!(Rc::strong_count(&value), 4);
+
+    drop((list, other, tail));
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    let (matching, rest) = sl_list![value.clone(), value.clone()].partition(|_| true);
+    drop((matching, rest, sl_list![value.clone()].into_iter_rev()));
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn truncate() {
+    let mut list = SinglyLinkedList::from(["a", "b", "c", "d"].map(String::from));
+
+    list.truncate(5);
+    assert_eq!(list.len(), 4);
+
+    list.truncate(2);
+    assert_eq!(list, ["a", "b"].map(String::from));
+    assert_eq!(list.back(), Some(&String::from("b")));
+
+    list.push_back(String::from("e"));
+    assert_eq!(list, ["a", "b", "e"].map(String::from));
+
+    list.truncate(0);
+    assert_eq!((list.len(), list.front(), list.back()), (0, None, None));
+}
+
+
+#[test]
+fn dedup() {
+    let mut list = SinglyLinkedList::from(["a", "a", "b", "c", "c", "a", "a"].map(String::from));
+    list.dedup();
+
+    assert_eq!(list, ["a", "b", "c", "a"].map(String::from));
+    assert_eq!((list.len(), list.back()), (4, Some(&String::from("a"))));
+
+    list.push_back(String::from("d"));
+    assert_eq!(list.back(), Some(&String::from("d")));
+
+    let mut single = sl_list![1, 1, 1];
+    single.dedup();
+    assert_eq!((single.len(), single.front(), single.back()), (1, Some(&1), Some(&1)));
+
+    let mut empty = SinglyLinkedList::<i32>::new();
+    empty.dedup();
+    assert!(empty.is_empty());
+}
+
+
+#[test]
+fn merge() {
+    let mut list = SinglyLinkedList::from(["a", "c", "e"].map(String::from));
+    let mut other = SinglyLinkedList::from(["b", "c", "f", "g"].map(String::from));
+
+    list.merge(&mut other);
+
+    assert_eq!(list, ["a", "b", "c", "c", "e", "f", "g"].map(String::from));
+    assert_eq!((list.len(), list.back()), (7, Some(&String::from("g"))));
+    assert_eq!((other.len(), other.front(), other.back()), (0, None, None));
+
+    let mut empty = SinglyLinkedList::new();
+    empty.merge(&mut list);
+    list.merge(&mut SinglyLinkedList::new());
+
+    assert_eq!(empty.len(), 7);
+    assert!(list.is_empty());
+
+    let mut low = sl_list![1, 2];
+    low.merge(&mut sl_list![3, 4]);
+    low.push_back(5);
+    assert_eq!(low, sl_list![1, 2, 3, 4, 5]);
+
+    let mut high = sl_list![3, 4];
+    high.merge(&mut sl_list![1, 2]);
+    high.push_back(5);
+    assert_eq!(high, sl_list![1, 2, 3, 4, 5]);
+}